@@ -0,0 +1,136 @@
+"""Deterministic function-level profiler.
+
+A pure-Python profiler in the style of CPython's `profile` module, built on
+`sys.setprofile`.  Per-function call counts and times are collected in the
+same `(filename, lineno, funcname) -> (cc, nc, tt, ct, callers)` shape that
+`profile.Profile.create_stats` produces, so simple consumers of that format
+work unchanged.
+
+Timing uses `time.perf_counter`, so all figures are wall-clock seconds.
+"""
+
+import sys
+import time
+
+__all__ = ["run", "runctx", "Profile", "Stats"]
+
+
+class Profile:
+    """Profiler recording per-function call counts and times.
+
+    Use `runcall`/`run`/`runctx` to profile something, then `create_stats`
+    to snapshot the results into `self.stats`.
+    """
+
+    def __init__(self, timer=None):
+        self.timer = timer if timer is not None else time.perf_counter
+        # (filename, lineno, funcname) -> [callcount, tottime, cumtime]
+        self.timings = {}
+        # stack of [key, start time, time spent in callees]
+        self.cur = []
+        self.stats = {}
+
+    def _dispatch(self, frame, event, arg):
+        now = self.timer()
+        if event == "call":
+            code = frame.f_code
+            key = (code.co_filename, code.co_firstlineno, code.co_name)
+            self.cur.append([key, now, 0.0])
+        elif event == "return" and self.cur:
+            key, start, callee_time = self.cur.pop()
+            elapsed = now - start
+            callcount, tottime, cumtime = self.timings.get(key, (0, 0.0, 0.0))
+            self.timings[key] = (
+                callcount + 1,
+                tottime + elapsed - callee_time,
+                cumtime + elapsed,
+            )
+            if self.cur:
+                self.cur[-1][2] += elapsed
+        # c_call/c_return/c_exception are ignored; builtin calls are charged
+        # to their caller
+
+    def enable(self):
+        self.cur = []
+        sys.setprofile(self._dispatch)
+
+    def disable(self):
+        sys.setprofile(None)
+        self.cur = []
+
+    def runcall(self, func, *args, **kw):
+        self.enable()
+        try:
+            return func(*args, **kw)
+        finally:
+            self.disable()
+
+    def run(self, cmd):
+        import __main__
+
+        dict = __main__.__dict__
+        return self.runctx(cmd, dict, dict)
+
+    def runctx(self, cmd, globals, locals):
+        code = compile(cmd, "<string>", "exec")
+        self.enable()
+        try:
+            exec(code, globals, locals)
+        finally:
+            self.disable()
+        return self
+
+    def create_stats(self):
+        # cc == nc: this profiler doesn't separate out recursive calls
+        self.stats = {
+            key: (cc, cc, tt, ct, {})
+            for key, (cc, tt, ct) in self.timings.items()
+        }
+        return self.stats
+
+    def print_stats(self, stream=None):
+        Stats(self).print_stats(stream)
+
+
+class Stats:
+    """Snapshot of a profiling run, sortable and printable."""
+
+    def __init__(self, profile):
+        profile.create_stats()
+        self.stats = dict(profile.stats)
+
+    def sorted_entries(self):
+        """Entries as (key, cc, nc, tt, ct), most cumulative time first."""
+        entries = [(key, *values[:4]) for key, values in self.stats.items()]
+        entries.sort(key=lambda entry: entry[4], reverse=True)
+        return entries
+
+    def print_stats(self, stream=None):
+        if stream is None:
+            stream = sys.stdout
+        print("   ncalls  tottime  cumtime filename:lineno(function)", file=stream)
+        for (filename, lineno, funcname), _cc, nc, tt, ct in self.sorted_entries():
+            print(
+                "%9d %8.3f %8.3f %s:%d(%s)" % (nc, tt, ct, filename, lineno, funcname),
+                file=stream,
+            )
+
+
+def run(statement):
+    """Profile `statement` in the `__main__` environment and print stats."""
+    prof = Profile()
+    try:
+        prof.run(statement)
+    except SystemExit:
+        pass
+    prof.print_stats()
+
+
+def runctx(statement, globals, locals):
+    """Like `run`, but in the given environment."""
+    prof = Profile()
+    try:
+        prof.runctx(statement, globals, locals)
+    except SystemExit:
+        pass
+    prof.print_stats()
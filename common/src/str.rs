@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::ops::{Bound, RangeBounds};
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -7,6 +8,16 @@ pub type wchar_t = libc::wchar_t;
 #[allow(non_camel_case_types)]
 pub type wchar_t = u32;
 
+/// Get the length of a string in characters. All-ASCII strings (checked with
+/// a byte scan) short-circuit to the byte length instead of counting chars.
+pub fn char_len(s: &str) -> usize {
+    if s.is_ascii() {
+        s.len()
+    } else {
+        s.chars().count()
+    }
+}
+
 pub fn try_get_chars(s: &str, range: impl RangeBounds<usize>) -> Option<&str> {
     let mut chars = s.chars();
     let start = match range.start_bound() {
@@ -30,6 +41,46 @@ pub fn get_chars(s: &str, range: impl RangeBounds<usize>) -> &str {
     try_get_chars(s, range).unwrap()
 }
 
+/// Get a substring by a range of extended grapheme clusters, so that e.g.
+/// combining marks stay attached to their base character. Like
+/// [`try_get_chars`], returns `None` when the range falls outside the string.
+/// Pure-ASCII input takes the `try_get_chars` fast path, since every byte is
+/// its own cluster there.
+#[cfg(feature = "graphemes")]
+pub fn try_get_graphemes(s: &str, range: impl RangeBounds<usize>) -> Option<&str> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    if s.is_ascii() {
+        return try_get_chars(s, range);
+    }
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i + 1,
+        Bound::Unbounded => 0,
+    };
+    let mut graphemes = s.grapheme_indices(true);
+    let begin = match start.checked_sub(1) {
+        Some(n) => {
+            let (i, g) = graphemes.nth(n)?;
+            i + g.len()
+        }
+        None => 0,
+    };
+    let range_len = match range.end_bound() {
+        Bound::Included(&i) => i + 1 - start,
+        Bound::Excluded(&i) => i - start,
+        Bound::Unbounded => return Some(&s[begin..]),
+    };
+    let end = match range_len.checked_sub(1) {
+        Some(n) => {
+            let (i, g) = graphemes.nth(n)?;
+            i + g.len()
+        }
+        None => begin,
+    };
+    Some(&s[begin..end])
+}
+
 #[inline]
 pub fn char_range_end(s: &str, nchars: usize) -> Option<usize> {
     let i = match nchars.checked_sub(1) {
@@ -60,13 +111,14 @@ pub fn zfill(bytes: &[u8], width: usize) -> Vec<u8> {
     }
 }
 
-/// Convert a string to ascii compatible, escaping unicodes into escape
-/// sequences.
-pub fn to_ascii(value: &str) -> String {
-    let mut ascii = String::new();
+fn escape_unrepresentable(value: &str, representable: impl Fn(char) -> bool) -> Cow<str> {
+    if value.chars().all(&representable) {
+        return Cow::Borrowed(value);
+    }
+    let mut escaped = String::new();
     for c in value.chars() {
-        if c.is_ascii() {
-            ascii.push(c)
+        if representable(c) {
+            escaped.push(c)
         } else {
             let c = c as i64;
             let hex = if c < 0x100 {
@@ -76,10 +128,39 @@ pub fn to_ascii(value: &str) -> String {
             } else {
                 format!("\\U{:08x}", c)
             };
-            ascii.push_str(&hex)
+            escaped.push_str(&hex)
         }
     }
-    ascii
+    Cow::Owned(escaped)
+}
+
+/// Convert a string to ascii compatible, escaping unicodes into escape
+/// sequences. Strings that are already all-ASCII are borrowed unchanged.
+pub fn to_ascii(value: &str) -> Cow<str> {
+    if value.is_ascii() {
+        return Cow::Borrowed(value);
+    }
+    escape_unrepresentable(value, |c| c.is_ascii())
+}
+
+/// Escape the characters of an already-`repr`ed string that the given target
+/// encoding can't represent, for output destined for a non-unicode terminal.
+/// Only a few well-known narrow encodings are recognized; anything else is
+/// assumed to cover all of unicode and the input is returned unchanged.
+pub fn repr_for_encoding<'a>(s: &'a str, encoding: &str) -> Cow<'a, str> {
+    // encoding names compare case-insensitively, with '-' and '_' equivalent
+    let normalized: String = encoding
+        .trim()
+        .chars()
+        .map(|c| if c == '_' { '-' } else { c.to_ascii_lowercase() })
+        .collect();
+    match normalized.as_str() {
+        "ascii" | "us-ascii" | "646" => to_ascii(s),
+        "latin-1" | "latin1" | "iso-8859-1" | "iso8859-1" => {
+            escape_unrepresentable(s, |c| (c as u32) < 0x100)
+        }
+        _ => Cow::Borrowed(s),
+    }
 }
 
 #[doc(hidden)]
@@ -109,6 +190,73 @@ macro_rules! ascii {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_try_get_graphemes() {
+        let s = "0123456789";
+        assert_eq!(try_get_graphemes(s, 3..7), Some("3456"));
+
+        // a combining acute accent stays with its base character
+        let s = "ab4e\u{301}f";
+        assert_eq!(try_get_graphemes(s, 2..4), Some("4e\u{301}"));
+        assert_eq!(try_get_graphemes(s, 3..), Some("e\u{301}f"));
+        assert_eq!(try_get_graphemes(s, 4..5), Some("f"));
+        assert_eq!(try_get_graphemes(s, 4..6), None);
+
+        // a family emoji ZWJ sequence is a single cluster
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f466}";
+        let s = format!("a{}b", family);
+        assert_eq!(try_get_graphemes(&s, 1..2), Some(family));
+        assert_eq!(try_get_graphemes(&s, 2..3), Some("b"));
+        assert_eq!(try_get_graphemes(&s, ..), Some(&*s));
+    }
+
+    #[test]
+    fn test_char_len() {
+        let s = "0123456789";
+        assert_eq!(char_len(s), s.chars().count());
+
+        let s = "";
+        assert_eq!(char_len(s), 0);
+
+        let s = "0유니코드 문자열9";
+        assert_eq!(char_len(s), s.chars().count());
+
+        let s = "0😀😃😄😁😆😅😂🤣9";
+        assert_eq!(char_len(s), s.chars().count());
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        // ASCII input comes back borrowed...
+        let s = "plain ascii";
+        assert!(matches!(to_ascii(s), Cow::Borrowed(b) if b == s));
+
+        // ...while non-ASCII characters get escaped into an owned string
+        assert!(matches!(
+            to_ascii("caf\u{e9} \u{1f600}"),
+            Cow::Owned(o) if o == "caf\\xe9 \\U0001f600"
+        ));
+    }
+
+    #[test]
+    fn test_repr_for_encoding() {
+        let s = "'caf\u{e9} \u{1f600}'";
+
+        // an ASCII terminal gets everything above 0x7f escaped
+        assert_eq!(
+            repr_for_encoding(s, "ascii"),
+            "'caf\\xe9 \\U0001f600'"
+        );
+        // latin-1 can hold the e-acute but not the emoji
+        assert_eq!(
+            repr_for_encoding(s, "Latin_1"),
+            "'caf\u{e9} \\U0001f600'"
+        );
+        // utf-8 (or anything unrecognized) passes through unescaped
+        assert!(matches!(repr_for_encoding(s, "utf-8"), Cow::Borrowed(b) if b == s));
+    }
+
     #[test]
     fn test_get_chars() {
         let s = "0123456789";
@@ -61,7 +61,7 @@ pub(crate) fn impl_pyimpl(
                 with_impl,
                 flags,
                 with_slots,
-            } = extract_impl_attrs(attr, &Ident::new(&quote!(ty).to_string(), ty.span()))?;
+            } = extract_impl_attrs(attr)?;
 
             let getset_impl = &context.getset_items;
             let extend_impl = &context.impl_extend_items;
@@ -97,7 +97,7 @@ pub(crate) fn impl_pyimpl(
                 with_impl,
                 with_slots,
                 ..
-            } = extract_impl_attrs(attr, &trai.ident)?;
+            } = extract_impl_attrs(attr)?;
 
             let getset_impl = &context.getset_items;
             let extend_impl = &context.impl_extend_items;
@@ -873,7 +873,6 @@ struct ExtractedImplAttrs {
 
 fn extract_impl_attrs(
     attr: AttributeArgs,
-    item: &Ident,
 ) -> std::result::Result<ExtractedImplAttrs, Diagnostic> {
     let mut withs = Vec::new();
     let mut with_slots = Vec::new();
@@ -901,14 +900,14 @@ fn extract_impl_attrs(
                             withs.push(quote_spanned! { path.span() =>
                                 PyRef::<Self>::impl_extend_class(ctx, class);
                             });
-                            with_slots.push(quote_spanned! { item.span() =>
+                            with_slots.push(quote! {
                                 PyRef::<Self>::extend_slots(slots);
                             });
                         } else {
                             withs.push(quote_spanned! { path.span() =>
                                 <Self as #path>::__extend_py_class(ctx, class);
                             });
-                            with_slots.push(quote_spanned! { item.span() =>
+                            with_slots.push(quote! {
                                 <Self as #path>::__extend_slots(slots);
                             });
                         }
@@ -70,3 +70,33 @@ assert ls == [
     "hello3",
     "hello4",
 ]
+
+# coroutine introspection
+class SuspendPoint:
+    def __await__(self):
+        yield self
+
+async def helper_coro():
+    await SuspendPoint()
+
+async def outer_coro():
+    await helper_coro()
+
+c = outer_coro()
+assert c.__name__ == 'outer_coro'
+assert c.__qualname__ == 'outer_coro'
+assert c.cr_running is False
+assert c.cr_await is None  # not suspended on anything yet
+assert c.cr_frame is not None
+
+suspended = c.send(None)  # drive to the inner await point
+assert isinstance(suspended, SuspendPoint)
+assert c.cr_await is not None
+assert c.cr_await.__name__ == 'helper_coro'
+
+try:
+    c.send(None)
+except StopIteration:
+    pass
+assert c.cr_await is None
+assert c.cr_frame is None  # finished coroutines have no frame
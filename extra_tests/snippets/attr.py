@@ -98,3 +98,32 @@ with assert_raises(KeyError):
     getattr(GetRaise(KeyError()), 'a')
 with assert_raises(KeyError):
     getattr(GetRaise(KeyError()), 'a', 11)
+
+
+class PropRaise:
+    @property
+    def boom(self):
+        raise RuntimeError("boom")
+
+
+# a default only applies to AttributeError; other exceptions propagate
+with assert_raises(RuntimeError):
+    getattr(PropRaise(), 'boom', 42)
+with assert_raises(RuntimeError):
+    hasattr(PropRaise(), 'boom')
+
+
+delattr_log = []
+
+
+class DelHook:
+    def __delattr__(self, name):
+        delattr_log.append(name)
+        object.__delattr__(self, name)
+
+
+d = DelHook()
+object.__setattr__(d, 'x', 1)
+delattr(d, 'x')
+assert delattr_log == ['x']
+assert not hasattr(d, 'x')
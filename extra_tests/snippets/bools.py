@@ -182,3 +182,18 @@ with assert_raises(ValueError):
 
 with assert_raises(TypeError):
     bool(TestLenThrowError())
+
+# bool keeps int semantics: arithmetic promotes to int...
+assert issubclass(bool, int)
+assert True + True == 2
+assert type(True + True) is int
+assert type(True + 1) is int
+
+# ...but bitwise ops between two bools stay bool
+assert type(True & False) is bool
+assert type(True | False) is bool
+assert type(True ^ True) is bool
+assert type(True & 1) is int
+
+assert repr(True) == 'True'
+assert repr(False) == 'False'
@@ -36,3 +36,18 @@ assert eval("dir()", {}, A()) == [5, 6]
 import socket
 
 assert "AF_INET" in dir(socket)
+
+# dir(obj) output is sorted
+d = dir(a)
+assert d == sorted(d)
+
+# object.__dir__ provides the default implementation dir() sorts
+assert sorted(object.__dir__(a)) == dir(a)
+
+# with no argument, dir() lists the current local namespace
+def local_names():
+    spam = 1
+    eggs = 2
+    return dir()
+
+assert local_names() == ['eggs', 'spam']
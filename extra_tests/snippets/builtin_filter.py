@@ -30,3 +30,28 @@ def predicate(x):
 
 
 assert list(filter(predicate, [1, 2, 0, 4, 5])) == [1, 2]
+
+
+# the predicate can drop any number of items, so the length hint is unknown
+import operator
+assert operator.length_hint(filter(None, [1, 2, 3])) == 0
+
+# a partially-consumed filter can be pickled and resumed
+import pickle
+
+partway = filter(operator.truth, [0, 1, 0, 2, 3])
+assert next(partway) == 1
+resumed = pickle.loads(pickle.dumps(partway))
+assert list(resumed) == [2, 3]
+
+
+# a filter subclass keeps its class through pickling
+class FilterSubclass(filter):
+    pass
+
+
+sub = FilterSubclass(operator.truth, [0, 1, 0, 2, 3])
+assert next(sub) == 1
+sub_resumed = pickle.loads(pickle.dumps(sub))
+assert type(sub_resumed) is FilterSubclass
+assert list(sub_resumed) == [2, 3]
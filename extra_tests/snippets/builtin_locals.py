@@ -13,7 +13,28 @@ def f():
 
 	loc = locals()
 
-	assert loc['a'] == 4
-	assert loc['c'] == 7
+	assert loc['a'] == 7
+	assert loc['c'] == 4
 	assert not 'b' in loc
 
+f()
+
+# at module scope, locals() and globals() are the same (live) namespace
+assert locals() is globals()
+
+# names written through the globals() dict become visible as globals
+globals()['injected'] = 99
+assert injected == 99
+
+def g():
+	x = 1
+	# locals() reflects the locals at the time of the call
+	before = locals()
+	assert before == {'x': 1}
+	y = 2
+	after = locals()
+	assert after['y'] == 2
+	# a function's locals() is not the module namespace
+	assert locals() is not globals()
+
+g()
@@ -32,3 +32,29 @@ def mapping(x):
 
 
 assert list(map(mapping, [1, 2, 0, 4, 5])) == [1, 2]
+
+
+# chunksize batching is a RustPython extension
+import platform
+if platform.python_implementation() == 'RustPython':
+    from testutils import assert_raises
+
+    assert list(map(lambda x: x * 2, range(10), chunksize=3)) == \
+        list(map(lambda x: x * 2, range(10)))
+    assert list(map(lambda x, y: x + y, [1, 2, 3], [10, 20], chunksize=4)) == [11, 22]
+
+    def raising(x):
+        if x == 3:
+            raise ValueError('batched')
+        return x
+
+    # an exception raised mid-batch still surfaces at the right element
+    chunked = map(raising, range(10), chunksize=4)
+    assert next(chunked) == 0
+    assert next(chunked) == 1
+    assert next(chunked) == 2
+    with assert_raises(ValueError):
+        next(chunked)
+
+    with assert_raises(ValueError):
+        map(raising, [], chunksize=0)
@@ -29,3 +29,21 @@ class X:
 assert round(X(), 1) == 1.1
 assert round(X(), None) == 1.1
 assert round(X()) == 1.1
+
+# ndigits=None or non-negative leaves the int unchanged
+assert round(12345) == 12345
+assert round(12345, None) == 12345
+assert round(12345, 2) == 12345
+
+# negative ndigits rounds to a multiple of ten, returning an int
+assert round(12345, -2) == 12300
+assert round(12399, -2) == 12400
+assert isinstance(round(12345, -2), int)
+assert round(-12345, -2) == -12300
+# ties go to the even multiple
+assert round(12350, -2) == 12400
+assert round(12250, -2) == 12200
+assert round(5, -1) == 0
+assert round(15, -1) == 20
+# rounding away more digits than the number has gives 0
+assert round(12345, -10) == 0
@@ -22,3 +22,24 @@ class Counter(object):
 it = zip(Counter(), Counter(3))
 assert next(it) == (1, 4)
 assert next(it) == (2, 5)
+
+
+# a partially-consumed zip can be pickled and resumed
+import pickle
+
+partway = zip([1, 2, 3, 4], [10, 20, 30, 40])
+assert next(partway) == (1, 10)
+resumed = pickle.loads(pickle.dumps(partway))
+assert list(resumed) == [(2, 20), (3, 30), (4, 40)]
+
+
+# a zip subclass keeps its class through pickling
+class ZipSubclass(zip):
+    pass
+
+
+sub = ZipSubclass([1, 2, 3], [10, 20, 30])
+assert next(sub) == (1, 10)
+sub_resumed = pickle.loads(pickle.dumps(sub))
+assert type(sub_resumed) is ZipSubclass
+assert list(sub_resumed) == [(2, 20), (3, 30)]
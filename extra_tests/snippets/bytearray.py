@@ -601,8 +601,14 @@ assert bytearray(b"123456789123").replace(b"23", bytearray(b"")) == bytearray(b"
 
 # clear
 a = bytearray(b"abcd")
-a.clear()
+assert a.clear() is None
 assert len(a) == 0
+assert a == bytearray()
+
+# the cleared buffer is still usable in place
+a.append(65)
+a.extend(b"BC")
+assert a == bytearray(b"ABC")
 
 b = bytearray(b"test")
 assert len(b) == 4
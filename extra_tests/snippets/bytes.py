@@ -167,7 +167,7 @@ assert [b"kok".center(i, b"|") for i in range(2, 10)] == [
     b"||kok|||",
     b"|||kok|||",
 ]
-b"kok".center(4) == b" kok"  # " test no arg"
+assert b"kok".center(4) == b"kok "  # test no arg: space fill
 with assert_raises(TypeError):
     b"b".center(2, "a")
 with assert_raises(TypeError):
@@ -175,7 +175,7 @@ with assert_raises(TypeError):
 with assert_raises(TypeError):
     b"b".center(b"ba")
 assert b"kok".center(5, bytearray(b"x")) == b"xkokx"
-b"kok".center(-5) == b"kok"
+assert b"kok".center(-5) == b"kok"
 
 
 # ljust
@@ -199,7 +199,7 @@ assert [b"kok".ljust(i, b"|") for i in range(2, 10)] == [
     b"kok||||||",
 ]
 
-b"kok".ljust(4) == b"kok "  # " test no arg"
+assert b"kok".ljust(4) == b"kok "  # test no arg: space fill
 with assert_raises(TypeError):
     b"b".ljust(2, "a")
 with assert_raises(TypeError):
@@ -231,7 +231,7 @@ assert [b"kok".rjust(i, b"|") for i in range(2, 10)] == [
 ]
 
 
-b"kok".rjust(4) == b" kok"  # " test no arg"
+assert b"kok".rjust(4) == b" kok"  # test no arg: space fill
 with assert_raises(TypeError):
     b"b".rjust(2, "a")
 with assert_raises(TypeError):
@@ -260,7 +260,12 @@ assert b"azeazerazeazopia".count(b"aze", -13, -10) == 1
 assert b"azeazerazeazopia".count(b"aze", 1, 10000) == 2
 with assert_raises(ValueError):
     b"ilj".count(3550)
+with assert_raises(ValueError):
+    b"ilj".count(-1)
 assert b"azeazerazeazopia".count(97) == 5
+assert b"azeazerazeazopia".count(97, 9) == 2
+assert b"azeazerazeazopia".count(97, 1, 3) == 0
+assert b"aaaa".count(b"aa") == 2  # occurrences don't overlap
 
 # join
 assert (
@@ -347,6 +352,16 @@ assert b" \n  spacious   ".lstrip() == b"spacious   "
 assert b"www.example.com".lstrip(b"cmowz.") == b"example.com"
 assert b"   spacious \n  ".rstrip() == b"   spacious"
 assert b"mississippi".rstrip(b"ipz") == b"mississ"
+assert b"\t\r\nspacious\x0b\x0c ".strip() == b"spacious"
+assert b"abcabc".strip(b"cba") == b""
+assert b"mississippi".strip(bytearray(b"ipz")) == b"mississ"
+assert b"abc".strip(None) == b"abc"
+with assert_raises(TypeError):
+    b"abc".strip("ab")
+with assert_raises(TypeError):
+    b"abc".lstrip("a")
+with assert_raises(TypeError):
+    b"abc".rstrip("c")
 
 
 # split
@@ -569,6 +584,13 @@ assert b"ab c\n\nde fg\rkl\r\n".splitlines(keepends=True) == [
 ]
 assert b"".splitlines() == []
 assert b"One line\n".splitlines() == [b"One line"]
+assert b"a\r\nb".splitlines() == [b"a", b"b"]
+assert b"a\r\nb".splitlines(keepends=True) == [b"a\r\n", b"b"]
+assert b"a\n\rb".splitlines(keepends=True) == [b"a\n", b"\r", b"b"]
+# unlike str, bytes only splits on \r, \n and \r\n, not on the other
+# unicode line boundaries
+assert b"a\x0bb\x1cc\x85d".splitlines() == [b"a\x0bb\x1cc\x85d"]
+assert "a\u2028b".encode("utf-8").splitlines() == ["a\u2028b".encode("utf-8")]
 
 # zfill
 
@@ -586,6 +608,11 @@ assert b"123456789123".replace(b"23", b"") == b"14567891"
 assert b"123456789123".replace(b"23", b"X") == b"1X4567891X"
 assert b"rust  python".replace(b" ", b"-") == b"rust--python"
 assert b"rust  python".replace(b"  ", b"-") == b"rust-python"
+# empty old-pattern inserts between every byte, bounded by count
+assert b"abc".replace(b"", b"-") == b"-a-b-c-"
+assert b"abc".replace(b"", b"-", 2) == b"-a-bc"
+assert b"".replace(b"", b"-") == b"-"
+assert b"aaa".replace(bytearray(b"a"), memoryview(b"b"), 1) == b"baa"
 
 # title
 assert b"Hello world".title() == b"Hello World"
@@ -287,3 +287,85 @@ assert type(type.mro).__name__ == 'method_descriptor'
 
 B('x', (int,), {}).x()
 A()
+
+
+# __init_subclass__ is implicitly a classmethod and receives each new
+# subclass as cls, even without the decorator
+init_subclass_seen = []
+
+class InitSubclassBase:
+    def __init_subclass__(cls, **kwargs):
+        super().__init_subclass__(**kwargs)
+        init_subclass_seen.append(cls)
+
+class InitSubclassChild(InitSubclassBase):
+    pass
+
+class InitSubclassGrandchild(InitSubclassChild):
+    pass
+
+assert init_subclass_seen == [InitSubclassChild, InitSubclassGrandchild]
+
+# decorating it explicitly behaves the same
+decorated_seen = []
+
+class DecoratedBase:
+    @classmethod
+    def __init_subclass__(cls, **kwargs):
+        super().__init_subclass__(**kwargs)
+        decorated_seen.append(cls)
+
+class DecoratedChild(DecoratedBase):
+    pass
+
+assert decorated_seen == [DecoratedChild]
+
+# keyword arguments from the class statement are passed through
+class KeywordBase:
+    def __init_subclass__(cls, flavor=None, **kwargs):
+        super().__init_subclass__(**kwargs)
+        cls.flavor = flavor
+
+class KeywordChild(KeywordBase, flavor='sweet'):
+    pass
+
+assert KeywordChild.flavor == 'sweet'
+
+
+class BoundTarget:
+    def method(self):
+        return 42
+
+
+bt = BoundTarget()
+bound = bt.method
+assert bound.__func__ is BoundTarget.method
+assert bound.__self__ is bt
+assert bound() == 42
+
+# two bound methods wrapping the same function and instance compare equal
+assert bound == bt.method
+assert not (bound != bt.method)
+assert bound != BoundTarget().method
+
+
+class SuperBase:
+    def who(self):
+        return "base"
+
+
+class SuperChild(SuperBase):
+    def who(self):
+        return "child"
+
+    def probe(self):
+        return super()
+
+
+sup = SuperChild().probe()
+assert sup.__self__.__class__ is SuperChild
+assert sup.__self_class__ is SuperChild
+assert sup.__thisclass__ is SuperChild
+# lookup starts after __thisclass__ in the MRO
+assert sup.who() == "base"
+assert super(SuperBase, SuperChild()).__self_class__ is SuperChild
@@ -21,3 +21,25 @@ def test_type_error(x, y):
 
 test_type_error([], 0)
 test_type_error((), 0)
+
+
+# when both sides return NotImplemented, == / != fall back to identity and
+# ordering comparisons raise TypeError
+class Incomparable:
+    def __eq__(self, other):
+        return NotImplemented
+
+    def __lt__(self, other):
+        return NotImplemented
+
+
+left = Incomparable()
+right = Incomparable()
+
+assert (left == left) is True
+assert (left != left) is False
+assert (left == right) is False
+assert (left != right) is True
+
+assert_raises(TypeError, lambda: left < right)
+test_type_error(left, right)
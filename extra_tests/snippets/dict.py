@@ -198,6 +198,24 @@ assert x.pop(1) == 'a'
 assert x.pop('1') is None
 assert x == {}
 
+x = {1: 'a'}
+assert x.pop(1, 'b') == 'a'
+assert x.pop(1, 'b') == 'b'
+assert x.pop(1, None) is None
+
+# pop hashes the key exactly once
+class CountedHash:
+    hashed = 0
+    def __hash__(self):
+        CountedHash.hashed += 1
+        return 1
+
+k = CountedHash()
+x = {k: 'a'}
+CountedHash.hashed = 0
+assert x.pop(k) == 'a'
+assert CountedHash.hashed == 1
+
 x = {1: 'a'}
 assert (1, 'a') == x.popitem()
 assert x == {}
@@ -205,6 +223,14 @@ with assert_raises(KeyError) as cm:
     x.popitem()
 assert cm.exception.args == ('popitem(): dictionary is empty',)
 
+# popitem removes pairs in LIFO order
+x = {'a': 1, 'b': 2, 'c': 3}
+assert x.popitem() == ('c', 3)
+assert x.popitem() == ('b', 2)
+assert x == {'a': 1}
+x['d'] = 4
+assert x.popitem() == ('d', 4)
+
 x = {'a': 4}
 assert 4 == x.setdefault('a', 0)
 assert x['a'] == 4
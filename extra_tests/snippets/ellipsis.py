@@ -31,3 +31,21 @@ else:
 assert Ellipsis is ...
 Ellipsis = 2
 assert Ellipsis is not ...
+
+del Ellipsis
+assert Ellipsis is ...  # the builtin shows through again
+
+assert repr(...) == 'Ellipsis'
+assert bool(...) is True
+
+# usable in subscripts and as a dict key
+class Subscript:
+    def __getitem__(self, item):
+        return item
+
+assert Subscript()[..., 0] == (Ellipsis, 0)
+
+d = {...: 1}
+assert d[Ellipsis] == 1
+
+assert type(...)() is ...
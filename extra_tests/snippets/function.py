@@ -101,3 +101,56 @@ def f9():
     pass
 
 assert f9.__doc__ == None
+
+
+import types
+
+def f10(a, b=5):
+    return a + b
+
+f10_copy = types.FunctionType(f10.__code__, globals(), "f10_copy", (7,))
+assert f10_copy.__name__ == "f10_copy"
+assert f10_copy.__defaults__ == (7,)
+assert f10_copy(1) == 8
+assert f10_copy(1, 2) == 3
+
+# the globals dict passed in is what the new function closes over
+namespace = {"base": 100}
+code = compile("def offset(n): return base + n", "<test>", "exec")
+exec(code, namespace)
+rebuilt = types.FunctionType(namespace["offset"].__code__, {"base": 1})
+assert rebuilt(5) == 6
+
+# a code object with free variables requires a matching closure
+def outer():
+    x = 1
+    def inner():
+        return x
+    return inner
+
+with assert_raises(TypeError):
+    types.FunctionType(outer().__code__, globals())
+
+# code.replace produces a modified copy, leaving the original untouched
+def f11(a, b):
+    return a - b
+
+patched = f11.__code__.replace(co_name="patched")
+assert patched.co_name == "patched"
+assert f11.__code__.co_name == "f11"
+
+f11_patched = types.FunctionType(patched, globals())
+assert f11_patched.__name__ == "patched"
+assert f11_patched(5, 3) == 2
+
+with assert_raises(ValueError):
+    # more arguments than co_varnames has slots for
+    f11.__code__.replace(co_argcount=100)
+
+# an explicit None name keeps the code object's name
+f10_none = types.FunctionType(f10.__code__, globals(), None, (7,))
+assert f10_none.__name__ == "f10"
+assert f10_none(1) == 8
+
+with assert_raises(ValueError):
+    f11.__code__.replace(co_argcount=-1)
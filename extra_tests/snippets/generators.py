@@ -27,6 +27,14 @@ assert 23 == next(i)
 assert 15 == i.send(10)
 assert 17 == i.send(10)
 
+# a just-started generator only accepts None
+i = g2(23)
+with assert_raises(TypeError) as cm:
+    i.send(10)
+assert "can't send non-None value to a just-started generator" in str(cm.exception)
+assert 23 == i.send(None)  # send(None) primes it like next()
+assert 15 == i.send(10)
+
 
 def g3():
     yield 23
@@ -168,3 +176,40 @@ assert next(g) == 1
 assert g.gi_yieldfrom is it
 assert list(g) == [2,3,4]
 assert g.gi_yieldfrom is None
+
+
+# PEP 479: a StopIteration leaking out of a generator body becomes RuntimeError
+def pep479():
+    yield 1
+    next(iter([]))
+    yield 2
+
+g = pep479()
+assert next(g) == 1
+with assert_raises(RuntimeError) as cm:
+    next(g)
+assert "generator raised StopIteration" in str(cm.exception)
+
+
+# generators carry introspection metadata from their defining function
+def outer():
+    def named_gen():
+        yield 1
+    return named_gen()
+
+g = outer()
+assert g.__name__ == 'named_gen'
+assert g.__qualname__ == 'outer.<locals>.named_gen'
+assert g.gi_running is False
+assert g.gi_code is not None
+assert g.gi_frame is not None
+
+# gi_frame becomes None once the generator is exhausted
+assert list(g) == [1]
+assert g.gi_frame is None
+
+# __name__/__qualname__ are writable
+g.__name__ = 'renamed'
+g.__qualname__ = 'outer.renamed'
+assert g.__name__ == 'renamed'
+assert 'outer.renamed' in repr(g)
@@ -70,3 +70,32 @@ from testutils import assert_raises
 with assert_raises(SyntaxError):
 	exec('import')
 
+
+# sys.modules is the live import cache: injecting an object makes `import`
+# return it, and removing an entry forces a fresh import attempt
+import sys
+import types
+
+fake = types.ModuleType("fake_mod")
+fake.answer = 42
+sys.modules["fake_mod"] = fake
+import fake_mod
+assert fake_mod is fake
+assert fake_mod.answer == 42
+del sys.modules["fake_mod"]
+
+with assert_raises(ImportError):
+    import fake_mod
+
+# the cached value doesn't even have to be a module
+sys.modules["not_a_module"] = 5
+import not_a_module
+assert not_a_module == 5
+del sys.modules["not_a_module"]
+
+# modules loaded from source carry import metadata
+assert import_target.__spec__ is not None
+assert import_target.__spec__.name == "import_target"
+assert import_target.__spec__.origin == import_target.__file__
+assert import_target.__loader__ is not None
+assert import_target.__spec__.loader is import_target.__loader__
@@ -9,3 +9,18 @@ assert next(i) == 3
 
 assert next(i, 'w00t') == 'w00t'
 
+
+# a for loop stops on StopIteration from the iterator itself, but a
+# StopIteration raised inside the loop body must propagate
+from testutils import assert_raises
+
+visited = []
+
+def body():
+    for x in [1, 2, 3]:
+        visited.append(x)
+        next(iter([]))
+
+with assert_raises(StopIteration):
+    body()
+assert visited == [1]
@@ -186,3 +186,31 @@ i = 7**500
 assert json.dumps(i) == str(i)
 
 assert json.decoder.scanstring('✨x"', 1) == ('x', 3)
+
+# objects the encoder can't handle natively go through `default`
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+def point_default(o):
+    if isinstance(o, Point):
+        return {'x': o.x, 'y': o.y}
+    raise TypeError('not serializable')
+
+assert json.loads(json.dumps({'p': Point(1, 2)}, default=point_default)) == {'p': {'x': 1, 'y': 2}}
+
+# or through a JSONEncoder subclass passed as `cls`
+class PointEncoder(json.JSONEncoder):
+    def default(self, o):
+        if isinstance(o, Point):
+            return [o.x, o.y]
+        return super().default(o)
+
+assert json.dumps(Point(3, 4), cls=PointEncoder) == '[3, 4]'
+
+with assert_raises(TypeError):
+    json.dumps(object())
+# a `default` that raises TypeError itself propagates it
+with assert_raises(TypeError):
+    json.dumps(object(), default=point_default)
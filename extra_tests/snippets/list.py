@@ -47,6 +47,36 @@ class Next:
 assert_raises(TypeError, lambda: [].extend(3))
 assert_raises(TypeError, lambda: [].extend(slice(0, 10, 1)))
 
+# extend uses __length_hint__ to reserve capacity up front; a generator has no
+# useful hint and a lying hint must not affect the result
+def gen():
+    yield 1
+    yield 2
+    yield 3
+
+a = [0]
+a.extend(gen())
+assert a == [0, 1, 2, 3]
+
+class WrongHint:
+    def __init__(self, hint):
+        self.hint = hint
+    def __iter__(self):
+        return iter([1, 2, 3])
+    def __length_hint__(self):
+        return self.hint
+
+a = []
+a.extend(WrongHint(1))
+assert a == [1, 2, 3]
+a = []
+a.extend(WrongHint(100))
+assert a == [1, 2, 3]
+
+a = [0]
+a.extend([1, 2, 3])
+assert a == [0, 1, 2, 3]
+
 assert x * 0 == [], "list __mul__ by 0 failed"
 assert x * -1 == [], "list __mul__ by -1 failed"
 assert x * 2 == [1, 2, 3, 1, 2, 3], "list __mul__ by 2 failed"
@@ -107,6 +137,11 @@ x = ['a', 'b', 'c']
 x.insert(-100, 'z')
 assert x == ['z', 'a', 'b', 'c']
 
+# out-of-range indices are clamped rather than raising IndexError
+x = ['a', 'b', 'c']
+x.insert(10 ** 9, 'z')
+assert x == ['a', 'b', 'c', 'z']
+
 assert_raises(OverflowError, lambda: x.insert(100000000000000000000, 'z'))
 
 x = [[], 2, {}]
@@ -67,3 +67,21 @@ def test_resizable():
     b.append(7)
 
 test_resizable()
+
+def test_cast():
+    m = memoryview(b'\x01\x00\x00\x00\x02\x00\x00\x00')
+    mi = m.cast('I')
+    assert mi.format == 'I'
+    assert mi.itemsize == 4
+    assert len(mi) == 2
+    assert mi[0] == 1
+    assert mi[1] == 2
+    # casting back restores the byte-level view
+    assert mi.cast('B').tobytes() == m.tobytes()
+
+    # the byte length must divide evenly into the new itemsize
+    assert_raises(TypeError, lambda: memoryview(b'abc').cast('I'))
+    # casts are restricted to contiguous views
+    assert_raises(TypeError, lambda: memoryview(b'abcdefgh')[::2].cast('I'))
+
+test_cast()
@@ -24,3 +24,9 @@ assert None.__ne__(3) is NotImplemented
 assert None.__eq__(None) is True
 assert None.__ne__(None) is False
 
+
+# constructing the singleton types returns the singletons themselves
+assert type(None)() is None
+assert type(NotImplemented)() is NotImplemented
+assert repr(NotImplemented) == 'NotImplemented'
+assert str(NotImplemented) == 'NotImplemented'
@@ -267,6 +267,16 @@ assert frozenset([1,2,3]) ^ frozenset([4,5]) == frozenset([1,2,3,4,5])
 assert frozenset([1,2,3]) ^ frozenset([1,2,3,4,5]) == frozenset([4,5])
 assert_raises(TypeError, lambda: frozenset([1,2,3]) ^ [1,2,3,4,5])
 
+# mixed set/frozenset operators return the type of the left operand
+assert type(frozenset([1]) - set([1])) is frozenset
+assert type(frozenset([1]) & set([1])) is frozenset
+assert type(frozenset([1]) | set([2])) is frozenset
+assert type(frozenset([1]) ^ set([2])) is frozenset
+assert type(set([1]) - frozenset([1])) is set
+assert type(set([1]) & frozenset([1])) is set
+assert type(set([1]) | frozenset([2])) is set
+assert type(set([1]) ^ frozenset([2])) is set
+
 assert frozenset([1,2,3]).isdisjoint(frozenset([5,6])) == True
 assert frozenset([1,2,3]).isdisjoint(frozenset([2,5,6])) == False
 assert frozenset([1,2,3]).isdisjoint([5,6]) == True
@@ -367,3 +377,20 @@ non_empty_set.remove(1)
 assert 1 not in non_empty_set
 
 # TODO: Assert that adding the same thing to a set once it's already there doesn't do anything.
+
+# plain `in` does not convert unhashable operands; it raises with the
+# standard unhashable message
+with assert_raises(TypeError) as cm:
+    [1] in {1, 2}
+assert str(cm.exception) == "unhashable type: 'list'"
+
+with assert_raises(TypeError) as cm:
+    {} in {1, 2}
+assert str(cm.exception) == "unhashable type: 'dict'"
+
+with assert_raises(TypeError) as cm:
+    [1] in frozenset({1, 2})
+assert str(cm.exception) == "unhashable type: 'list'"
+
+# but an unhashable *set* operand is converted for membership tests
+assert {1, 2} in {frozenset({1, 2})}
@@ -0,0 +1,49 @@
+import codecs
+
+from testutils import assert_raises
+
+# built-in error handlers are pre-registered
+assert codecs.lookup_error("strict") is codecs.strict_errors
+assert codecs.lookup_error("ignore") is codecs.ignore_errors
+assert codecs.lookup_error("replace") is codecs.replace_errors
+with assert_raises(LookupError):
+    codecs.lookup_error("no-such-handler")
+
+
+# custom handlers can be registered and used by name
+def question_mark(exc):
+    if not isinstance(exc, UnicodeError):
+        raise exc
+    return ("?", exc.end)
+
+
+codecs.register_error("question_mark", question_mark)
+assert codecs.lookup_error("question_mark") is question_mark
+
+assert "héllo".encode("ascii", errors="question_mark") == b"h?llo"
+assert b"h\xffllo".decode("ascii", errors="question_mark") == "h?llo"
+
+with assert_raises(UnicodeEncodeError):
+    "héllo".encode("ascii", errors="strict")
+assert "héllo".encode("ascii", errors="ignore") == b"hllo"
+assert "héllo".encode("ascii", errors="replace") == b"h?llo"
+
+# incremental codecs buffer multibyte sequences split across chunks
+decoder = codecs.getincrementaldecoder("utf-8")()
+data = "aé€".encode("utf-8")
+assert decoder.decode(data[:3]) == "aé"
+assert decoder.decode(data[3:5]) == ""  # incomplete euro sign is buffered
+assert decoder.decode(data[5:], final=True) == "€"
+
+decoder.reset()
+assert decoder.decode(b"\xe2\x82") == ""
+assert decoder.decode(b"\xac", final=True) == "€"
+
+# an incomplete sequence at the end is an error on the final call
+decoder.reset()
+assert decoder.decode(b"\xe2\x82") == ""
+with assert_raises(UnicodeDecodeError):
+    decoder.decode(b"", final=True)
+
+encoder = codecs.getincrementalencoder("utf-8")()
+assert encoder.encode("a") + encoder.encode("é") + encoder.encode("", final=True) == b"a\xc3\xa9"
@@ -0,0 +1,43 @@
+import doctest
+import io
+import sys
+
+
+def good():
+    """
+    >>> 1 + 1
+    2
+    """
+
+
+def bad():
+    """
+    >>> 2 + 2
+    5
+    """
+
+
+class Capture(io.StringIO):
+    # doctest consults sys.stdout.encoding when deciding how to write output
+    encoding = "utf-8"
+
+
+capture = Capture()
+save_stdout = sys.stdout
+sys.stdout = capture
+try:
+    results = doctest.testmod(verbose=False)
+finally:
+    sys.stdout = save_stdout
+
+assert results.attempted == 2
+assert results.failed == 1
+
+# the report shows the failing example with expected vs actual output
+report = capture.getvalue()
+assert "2 + 2" in report
+assert "Expected:" in report
+assert "Got:" in report
+
+# run_docstring_examples checks a single docstring
+doctest.run_docstring_examples(good, {}, name="good", verbose=False)
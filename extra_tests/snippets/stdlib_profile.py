@@ -0,0 +1,36 @@
+import io
+import profile
+
+
+def helper():
+    return sum(range(5))
+
+
+def work():
+    total = 0
+    for _ in range(10):
+        total += helper()
+    return total
+
+
+prof = profile.Profile()
+assert prof.runcall(work) == 100
+
+prof.create_stats()
+by_name = {key[2]: value for key, value in prof.stats.items()}
+
+cc, nc, tt, ct, callers = by_name["helper"]
+assert nc == 10
+assert cc <= nc
+assert 0.0 <= tt <= ct
+
+cc, nc, tt, ct, callers = by_name["work"]
+assert nc == 1
+# work's cumulative time covers the helper calls it made
+assert ct >= by_name["helper"][3] - by_name["helper"][2]
+
+# runctx profiles in a caller-provided namespace
+prof2 = profile.Profile()
+prof2.runctx("result = work()", globals(), None)
+prof2.create_stats()
+assert any(key[2] == "helper" for key in prof2.stats)
@@ -69,3 +69,115 @@ assert sys.getrecursionlimit() == 200
 
 with assert_raises(RecursionError):
     recursive_call(300)
+
+
+# sys.flags mirrors the options the interpreter was started with
+assert isinstance(sys.flags.optimize, int)
+assert sys.flags.optimize == 0  # the test suite runs without -O
+assert isinstance(sys.flags.debug, int)
+assert isinstance(sys.flags.verbose, int)
+assert isinstance(sys.flags.dont_write_bytecode, int)
+assert isinstance(sys.flags.hash_randomization, int)
+assert type(sys.flags).__name__ == "flags"
+
+
+# sys.displayhook receives the value of expressions run in 'single' mode
+shown = []
+save_displayhook = sys.displayhook
+sys.displayhook = shown.append
+try:
+    exec(compile("1 + 2", "<single>", "single"))
+finally:
+    sys.displayhook = save_displayhook
+assert shown == [3]
+
+# the default hook prints the repr, binds builtins._ and skips None
+import builtins
+import io
+
+capture = io.StringIO()
+save_stdout = sys.stdout
+sys.stdout = capture
+try:
+    exec(compile("'displayed'", "<single>", "single"))
+    exec(compile("None", "<single>", "single"))
+finally:
+    sys.stdout = save_stdout
+assert capture.getvalue() == "'displayed'\n"
+assert builtins._ == 'displayed'
+
+
+# encodings used by open() and path handling
+assert sys.getdefaultencoding() == 'utf-8'
+assert isinstance(sys.getfilesystemencoding(), str)
+assert len(sys.getfilesystemencoding()) > 0
+assert isinstance(sys.getfilesystemencodeerrors(), str)
+assert len(sys.getfilesystemencodeerrors()) > 0
+
+
+# sys.tracebacklimit caps the frames the default excepthook prints
+def tb_outer():
+    tb_inner()
+
+
+def tb_inner():
+    raise ValueError('limited')
+
+
+try:
+    tb_outer()
+except ValueError:
+    exc_info = sys.exc_info()
+
+
+def excepthook_output():
+    capture = io.StringIO()
+    save_stderr = sys.stderr
+    sys.stderr = capture
+    try:
+        sys.excepthook(*exc_info)
+    finally:
+        sys.stderr = save_stderr
+    return capture.getvalue()
+
+
+full = excepthook_output()
+assert full.count('File "') >= 3
+
+sys.tracebacklimit = 1
+limited = excepthook_output()
+assert limited.count('File "') == 1
+assert limited.startswith('Traceback (most recent call last):')
+assert limited.rstrip().endswith('ValueError: limited')
+
+# zero (or negative) suppresses the traceback body entirely
+sys.tracebacklimit = 0
+suppressed = excepthook_output()
+assert 'Traceback' not in suppressed
+assert suppressed.rstrip() == 'ValueError: limited'
+
+del sys.tracebacklimit
+
+
+# the displayhook escapes what stdout's declared encoding can't represent;
+# CPython only does this lazily on a real UnicodeEncodeError, so the eager
+# escaping is only observable with a StringIO stand-in under RustPython
+import platform
+if platform.python_implementation() == 'RustPython':
+    class EncodedOut(io.StringIO):
+        def __init__(self, encoding):
+            super().__init__()
+            self.encoding = encoding
+
+    def display_with_encoding(value, encoding):
+        out = EncodedOut(encoding)
+        save_stdout = sys.stdout
+        sys.stdout = out
+        try:
+            sys.displayhook(value)
+        finally:
+            sys.stdout = save_stdout
+        return out.getvalue()
+
+    assert display_with_encoding('emoji \U0001f600', 'ascii') == "'emoji \\U0001f600'\n"
+    assert display_with_encoding('emoji \U0001f600', 'utf-8') == "'emoji \U0001f600'\n"
@@ -0,0 +1,16 @@
+import timeit
+
+t = timeit.timeit("x = 1 + 1", number=1000)
+assert isinstance(t, float)
+assert t >= 0.0
+
+# setup runs once, the statement `number` times
+t = timeit.timeit("x.append(1)", setup="x = []", number=10)
+assert t >= 0.0
+
+r = timeit.repeat("x = 1 + 1", number=100, repeat=3)
+assert len(r) == 3
+assert all(isinstance(v, float) and v >= 0.0 for v in r)
+
+timer = timeit.Timer("x = 2 ** 8")
+assert timer.timeit(number=10) >= 0.0
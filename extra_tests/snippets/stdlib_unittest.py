@@ -0,0 +1,41 @@
+import io
+import unittest
+
+log = []
+
+
+class Example(unittest.TestCase):
+    def setUp(self):
+        log.append("setUp")
+
+    def tearDown(self):
+        log.append("tearDown")
+
+    def test_passes(self):
+        self.assertEqual(1 + 1, 2)
+        self.assertTrue(bool("x"))
+        with self.assertRaises(ZeroDivisionError):
+            1 / 0
+
+    def test_fails(self):
+        self.assertEqual(1, 2)
+
+
+loader = unittest.TestLoader()
+suite = loader.loadTestsFromTestCase(Example)
+
+stream = io.StringIO()
+runner = unittest.TextTestRunner(stream=stream, verbosity=0)
+result = runner.run(suite)
+
+assert result.testsRun == 2
+assert len(result.failures) == 1
+assert len(result.errors) == 0
+assert not result.wasSuccessful()
+failed_test, traceback_text = result.failures[0]
+assert failed_test._testMethodName == "test_fails"
+assert "AssertionError" in traceback_text
+
+# setUp/tearDown ran around every test, including the failing one
+assert log.count("setUp") == 2
+assert log.count("tearDown") == 2
@@ -79,6 +79,18 @@ assert s.strip('^*') == 'RustPython'
 assert s.lstrip('^*') == 'RustPython*^'
 assert s.rstrip('^*') == '^*RustPython'
 
+# chars is a set of characters, not a prefix/suffix
+assert 'www.example.com'.strip('cmowz.') == 'example'
+assert 'abcabc'.lstrip('cba') == ''
+assert 'spacious'.rstrip('suoi') == 'spac'
+assert 'mississippi'.rstrip('ipz') == 'mississ'
+
+# None means whitespace, and unicode whitespace is stripped too
+assert '  hallo  '.strip(None) == 'hallo'
+assert '\u00a0\thallo\u00a0\n'.strip() == 'hallo'
+assert '\u00a0hallo'.lstrip() == 'hallo'
+assert 'hallo\u00a0'.rstrip() == 'hallo'
+
 s = 'RustPython'
 assert s.ljust(8) == 'RustPython'
 assert s.rjust(8) == 'RustPython'
@@ -132,6 +144,15 @@ assert 'aaa'.count('a', 1, 2) == 1
 assert 'aaa'.count('a', 2, 2) == 0
 assert 'aaa'.count('a', 2, 1) == 0
 
+assert 'aaaa'.replace('a', 'b') == 'bbbb'
+assert 'aaaa'.replace('a', 'b', 2) == 'bbaa'
+assert 'aaaa'.replace('a', 'b', 0) == 'aaaa'
+assert 'aaaa'.replace('a', 'b', -1) == 'bbbb'  # negative count means all
+# an empty old-string inserts new between every character
+assert 'abc'.replace('', '-') == '-a-b-c-'
+assert 'abc'.replace('', '-', 2) == '-a-bc'
+assert ''.replace('', '-') == '-'
+
 assert '___a__'.find('a') == 3
 assert '___a__'.find('a', -10) == 3
 assert '___a__'.find('a', -3) == 3
@@ -192,6 +213,12 @@ assert not '123'.isidentifier()
 # String Formatting
 assert "{} {}".format(1, 2) == "1 2"
 assert "{0} {1}".format(2, 3) == "2 3"
+assert "{} {} {}".format("a", "b", "c") == "a b c"  # auto numbering is sequential
+assert "{:{}}{}".format(1, "d", 2) == "12"  # and keeps counting inside nested specs
+with assert_raises(ValueError):
+    "{} {0}".format(1, 2)
+with assert_raises(ValueError):
+    "{0} {}".format(1, 2)
 assert "--{:s>4}--".format(1) == "--sss1--"
 assert "{keyword} {0}".format(1, keyword=2) == "2 1"
 assert "repr() shows quotes: {!r}; str() doesn't: {!s}".format(
@@ -414,6 +441,20 @@ assert "1a".islower()
 assert "가나다a".islower()
 assert "가나다A".isupper()
 
+# at least one cased character is required
+assert not "123".isupper()
+assert not "123".islower()
+assert not "".isupper()
+assert not "".islower()
+assert not "123".istitle()
+
+# all cased characters must agree
+assert not "Hello".isupper()
+assert not "Hello".islower()
+assert "Hello World".istitle()
+assert not "Hello world".istitle()
+assert not "HELLO".istitle()
+
 # test str.format_map()
 #
 # The following tests were performed in Python 3.7.5:
@@ -727,3 +768,34 @@ assert id(b) != id(b * 0)
 assert id(b) != id(b * 1)
 assert id(b) != id(1 * b)
 assert id(b) != id(b * 2)
+
+# repetition edge cases
+assert "ab" * 3 == "ababab"
+assert 3 * "ab" == "ababab"
+assert "ab" * 0 == ""
+assert "ab" * -5 == ""
+rep = "hello"
+assert rep * 1 == rep
+
+# concatenation requires a str and names the other type
+with assert_raises(TypeError):
+    "a" + 1
+try:
+    "a" + 1
+except TypeError as e:
+    assert str(e) == 'can only concatenate str (not "int") to str'
+try:
+    "a" + b"b"
+except TypeError as e:
+    assert str(e) == 'can only concatenate str (not "bytes") to str'
+
+# __radd__ on the right operand still wins
+class Radd:
+    def __radd__(self, other):
+        return other + "!"
+
+assert "hi" + Radd() == "hi!"
+
+inplace = "x"
+inplace += "y"
+assert inplace == "xy"
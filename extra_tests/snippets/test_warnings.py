@@ -1,3 +1,19 @@
 import _warnings
 
 _warnings.warn("Test")
+
+import warnings
+
+from testutils import assert_raises
+
+# deprecated behavior in the vm goes through the warnings machinery, so
+# enabling error::DeprecationWarning turns it into an exception
+with warnings.catch_warnings():
+    warnings.simplefilter("error", DeprecationWarning)
+    with assert_raises(DeprecationWarning):
+        bool(NotImplemented)
+
+# without the filter it stays a warning
+with warnings.catch_warnings():
+    warnings.simplefilter("ignore")
+    assert bool(NotImplemented) is True
@@ -15,6 +15,13 @@ assert 3 * x == (1, 2, 1, 2, 1, 2)
 assert x * 0 == ()
 assert x * -1 == ()  # integers less than zero treated as 0
 
+assert (x * 1) is x  # multiplying by 1 returns the tuple itself
+assert (x * 0) is ()
+assert (x * -1) is ()
+
+assert_raises(TypeError, lambda: x + [3])
+assert_raises(TypeError, lambda: x + "ab")
+
 assert y < x, "tuple __lt__ failed"
 assert x > y, "tuple __gt__ failed"
 
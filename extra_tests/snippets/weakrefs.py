@@ -26,3 +26,45 @@ assert p.h == 5
 del g
 
 assert_raises(ReferenceError, lambda: p.h)
+
+
+class Container:
+    def __init__(self):
+        self.data = {"a": 1}
+
+    def get(self, key):
+        return self.data[key]
+
+    def __len__(self):
+        return len(self.data)
+
+    def __contains__(self, key):
+        return key in self.data
+
+    def __getitem__(self, key):
+        return self.data[key]
+
+    def __setitem__(self, key, value):
+        self.data[key] = value
+
+    def __delitem__(self, key):
+        del self.data[key]
+
+
+c = Container()
+cp = proxy(c)
+
+# method calls and operators go through to the referent
+assert cp.get("a") == 1
+assert len(cp) == 1
+assert "a" in cp
+assert cp["a"] == 1
+cp["b"] = 2
+assert c.data["b"] == 2
+del cp["b"]
+assert "b" not in c.data
+
+del c
+
+assert_raises(ReferenceError, lambda: cp.get("a"))
+assert_raises(ReferenceError, lambda: len(cp))
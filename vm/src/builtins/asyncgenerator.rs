@@ -31,9 +31,9 @@ impl PyAsyncGen {
         &self.inner
     }
 
-    pub fn new(frame: FrameRef, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, name: PyStrRef, qualname: PyStrRef) -> Self {
         PyAsyncGen {
-            inner: Coro::new(frame, Variant::AsyncGen, name),
+            inner: Coro::new(frame, Variant::AsyncGen, name, qualname),
             running_async: AtomicCell::new(false),
         }
     }
@@ -48,6 +48,16 @@ impl PyAsyncGen {
         self.inner.set_name(name)
     }
 
+    #[pyproperty(magic)]
+    fn qualname(&self) -> PyStrRef {
+        self.inner.qualname()
+    }
+
+    #[pyproperty(magic, setter)]
+    fn set_qualname(&self, qualname: PyStrRef) {
+        self.inner.set_qualname(qualname)
+    }
+
     #[pymethod(magic)]
     fn repr(zelf: PyRef<Self>) -> String {
         zelf.inner.repr(zelf.get_id())
@@ -111,8 +121,13 @@ impl PyAsyncGen {
         self.inner.frame().yield_from_target()
     }
     #[pyproperty]
-    fn ag_frame(&self, _vm: &VirtualMachine) -> FrameRef {
-        self.inner.frame()
+    fn ag_frame(&self, _vm: &VirtualMachine) -> Option<FrameRef> {
+        // no live frame once the async generator has finished
+        if self.inner.closed() {
+            None
+        } else {
+            Some(self.inner.frame())
+        }
     }
     #[pyproperty]
     fn ag_running(&self, _vm: &VirtualMachine) -> bool {
@@ -5,11 +5,12 @@
 use super::{PyStrRef, PyTypeRef};
 use crate::{
     bytecode::{self, BorrowedConstant, Constant, ConstantBag},
-    function::FuncArgs,
+    function::{FuncArgs, OptionalArg},
     IdProtocol, PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, StaticType,
     TypeProtocol, VirtualMachine,
 };
 use num_traits::Zero;
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops::Deref;
 
@@ -190,6 +191,22 @@ impl PyValue for PyCode {
 #[pyimpl(with(PyRef))]
 impl PyCode {}
 
+#[derive(FromArgs)]
+pub struct ReplaceArgs {
+    #[pyarg(named, optional)]
+    co_posonlyargcount: OptionalArg<isize>,
+    #[pyarg(named, optional)]
+    co_argcount: OptionalArg<isize>,
+    #[pyarg(named, optional)]
+    co_kwonlyargcount: OptionalArg<isize>,
+    #[pyarg(named, optional)]
+    co_filename: OptionalArg<PyStrRef>,
+    #[pyarg(named, optional)]
+    co_firstlineno: OptionalArg<usize>,
+    #[pyarg(named, optional)]
+    co_name: OptionalArg<PyStrRef>,
+}
+
 #[pyimpl]
 impl PyRef<PyCode> {
     #[pyslot]
@@ -260,6 +277,50 @@ impl PyRef<PyCode> {
             .collect();
         vm.ctx.new_tuple(varnames)
     }
+
+    #[pymethod]
+    fn replace(self, args: ReplaceArgs, vm: &VirtualMachine) -> PyResult<PyCode> {
+        let validate_count = |count: isize, name: &str| {
+            usize::try_from(count).map_err(|_| {
+                vm.new_value_error(format!("{} must be a positive integer", name))
+            })
+        };
+        let mut code = self.code.clone();
+        if let OptionalArg::Present(posonlyarg_count) = args.co_posonlyargcount {
+            code.posonlyarg_count = validate_count(posonlyarg_count, "co_posonlyargcount")?;
+        }
+        if let OptionalArg::Present(arg_count) = args.co_argcount {
+            code.arg_count = validate_count(arg_count, "co_argcount")?;
+        }
+        if let OptionalArg::Present(kwonlyarg_count) = args.co_kwonlyargcount {
+            code.kwonlyarg_count = validate_count(kwonlyarg_count, "co_kwonlyargcount")?;
+        }
+        if let OptionalArg::Present(source_path) = args.co_filename {
+            code.source_path = source_path;
+        }
+        if let OptionalArg::Present(first_line_number) = args.co_firstlineno {
+            code.first_line_number = first_line_number;
+        }
+        if let OptionalArg::Present(obj_name) = args.co_name {
+            code.obj_name = obj_name;
+        }
+
+        if code.posonlyarg_count > code.arg_count {
+            return Err(vm.new_value_error(
+                "co_posonlyargcount must not be greater than co_argcount".to_owned(),
+            ));
+        }
+        // every argument must have a slot in co_varnames
+        let total_args = code.arg_count
+            + code.kwonlyarg_count
+            + code.flags.contains(bytecode::CodeFlags::HAS_VARARGS) as usize
+            + code.flags.contains(bytecode::CodeFlags::HAS_VARKEYWORDS) as usize;
+        if total_args > code.varnames.len() {
+            return Err(vm.new_value_error("code: co_varnames is too small".to_owned()));
+        }
+
+        Ok(PyCode { code })
+    }
 }
 
 impl fmt::Display for PyCode {
@@ -25,9 +25,9 @@ impl PyCoroutine {
         &self.inner
     }
 
-    pub fn new(frame: FrameRef, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, name: PyStrRef, qualname: PyStrRef) -> Self {
         PyCoroutine {
-            inner: Coro::new(frame, Variant::Coroutine, name),
+            inner: Coro::new(frame, Variant::Coroutine, name, qualname),
         }
     }
 
@@ -41,6 +41,16 @@ impl PyCoroutine {
         self.inner.set_name(name)
     }
 
+    #[pyproperty(magic)]
+    fn qualname(&self) -> PyStrRef {
+        self.inner.qualname()
+    }
+
+    #[pyproperty(magic, setter)]
+    fn set_qualname(&self, qualname: PyStrRef) {
+        self.inner.set_qualname(qualname)
+    }
+
     #[pymethod(magic)]
     fn repr(zelf: PyRef<Self>) -> String {
         zelf.inner.repr(zelf.get_id())
@@ -82,8 +92,13 @@ impl PyCoroutine {
         self.inner.frame().yield_from_target()
     }
     #[pyproperty]
-    fn cr_frame(&self, _vm: &VirtualMachine) -> FrameRef {
-        self.inner.frame()
+    fn cr_frame(&self, _vm: &VirtualMachine) -> Option<FrameRef> {
+        // no live frame once the coroutine has finished
+        if self.inner.closed() {
+            None
+        } else {
+            Some(self.inner.frame())
+        }
     }
     #[pyproperty]
     fn cr_running(&self, _vm: &VirtualMachine) -> bool {
@@ -2,7 +2,7 @@ use super::PyTypeRef;
 use crate::{
     protocol::PyIter,
     slots::{IteratorIterable, SlotConstructor, SlotIterator},
-    PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, VirtualMachine,
+    PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, TypeProtocol, VirtualMachine,
 };
 
 /// filter(function or None, iterable) --> filter object
@@ -35,7 +35,26 @@ impl SlotConstructor for PyFilter {
 }
 
 #[pyimpl(with(SlotIterator, SlotConstructor), flags(BASETYPE))]
-impl PyFilter {}
+impl PyFilter {
+    #[pymethod(magic)]
+    fn length_hint(&self) -> usize {
+        // the predicate can drop any number of items, so the length is unknown
+        0
+    }
+
+    #[pymethod(magic)]
+    fn reduce(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyObjectRef {
+        // reconstruct with the instance's own class so subclasses survive
+        // the round-trip
+        vm.ctx.new_tuple(vec![
+            zelf.as_object().clone_class().into_object(),
+            vm.ctx.new_tuple(vec![
+                zelf.predicate.clone(),
+                zelf.iterator.as_object().clone(),
+            ]),
+        ])
+    }
+}
 
 impl IteratorIterable for PyFilter {}
 impl SlotIterator for PyFilter {
@@ -354,8 +354,19 @@ impl PyFloat {
     }
 
     #[pymethod(magic)]
-    fn repr(&self) -> String {
-        float_ops::to_string(self.value)
+    fn repr(&self, vm: &VirtualMachine) -> String {
+        match vm.state.settings.float_repr_precision {
+            Some(precision) => {
+                let formatted =
+                    float_ops::format_general(precision, self.value.abs(), float_ops::Case::Lower);
+                if self.value.is_sign_negative() && !self.value.is_nan() {
+                    format!("-{}", formatted)
+                } else {
+                    formatted
+                }
+            }
+            None => float_ops::to_string(self.value),
+        }
     }
 
     #[pymethod(magic)]
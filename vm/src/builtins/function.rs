@@ -36,6 +36,7 @@ pub struct PyFunction {
     closure: Option<PyTupleTyped<PyCellRef>>,
     defaults_and_kwdefaults: PyMutex<(Option<PyTupleRef>, Option<PyDictRef>)>,
     name: PyMutex<PyStrRef>,
+    qualname: PyMutex<PyStrRef>,
 }
 
 impl PyFunction {
@@ -47,6 +48,7 @@ impl PyFunction {
         kw_only_defaults: Option<PyDictRef>,
     ) -> Self {
         let name = PyMutex::new(code.obj_name.clone());
+        let qualname = PyMutex::new(code.obj_name.clone());
         PyFunction {
             code,
             #[cfg(feature = "jit")]
@@ -55,6 +57,7 @@ impl PyFunction {
             closure,
             defaults_and_kwdefaults: PyMutex::new((defaults, kw_only_defaults)),
             name,
+            qualname,
         }
     }
 
@@ -310,9 +313,15 @@ impl PyFunction {
         let is_gen = code.flags.contains(bytecode::CodeFlags::IS_GENERATOR);
         let is_coro = code.flags.contains(bytecode::CodeFlags::IS_COROUTINE);
         match (is_gen, is_coro) {
-            (true, false) => Ok(PyGenerator::new(frame, self.name()).into_object(vm)),
-            (false, true) => Ok(PyCoroutine::new(frame, self.name()).into_object(vm)),
-            (true, true) => Ok(PyAsyncGen::new(frame, self.name()).into_object(vm)),
+            (true, false) => {
+                Ok(PyGenerator::new(frame, self.name(), self.qualname()).into_object(vm))
+            }
+            (false, true) => {
+                Ok(PyCoroutine::new(frame, self.name(), self.qualname()).into_object(vm))
+            }
+            (true, true) => {
+                Ok(PyAsyncGen::new(frame, self.name(), self.qualname()).into_object(vm))
+            }
             (false, false) => vm.run_frame_full(frame),
         }
     }
@@ -328,7 +337,10 @@ impl PyValue for PyFunction {
     }
 }
 
-#[pyimpl(with(SlotDescriptor, Callable), flags(HAS_DICT, METHOD_DESCR))]
+#[pyimpl(
+    with(SlotDescriptor, Callable, SlotConstructor),
+    flags(HAS_DICT, METHOD_DESCR)
+)]
 impl PyFunction {
     #[pyproperty(magic)]
     fn code(&self) -> PyRef<PyCode> {
@@ -373,6 +385,16 @@ impl PyFunction {
         *self.name.lock() = name;
     }
 
+    #[pyproperty(magic)]
+    pub(crate) fn qualname(&self) -> PyStrRef {
+        self.qualname.lock().clone()
+    }
+
+    #[pyproperty(magic, setter)]
+    fn set_qualname(&self, qualname: PyStrRef) {
+        *self.qualname.lock() = qualname;
+    }
+
     #[pymethod(magic)]
     fn repr(zelf: PyRef<Self>, vm: &VirtualMachine) -> String {
         let qualname = vm
@@ -420,6 +442,57 @@ impl Callable for PyFunction {
     }
 }
 
+#[derive(FromArgs)]
+pub struct PyFunctionNewArgs {
+    #[pyarg(positional)]
+    code: PyRef<PyCode>,
+    #[pyarg(positional)]
+    globals: PyDictRef,
+    #[pyarg(any, optional)]
+    name: OptionalArg<Option<PyStrRef>>,
+    #[pyarg(any, optional)]
+    argdefs: OptionalArg<PyTupleRef>,
+    #[pyarg(any, optional)]
+    closure: OptionalArg<PyTupleTyped<PyCellRef>>,
+}
+
+impl SlotConstructor for PyFunction {
+    type Args = PyFunctionNewArgs;
+
+    fn py_new(cls: PyTypeRef, args: Self::Args, vm: &VirtualMachine) -> PyResult {
+        let nfree = args.code.freevars.len();
+        let closure = match args.closure.into_option() {
+            Some(closure) => {
+                if closure.len() != nfree {
+                    return Err(vm.new_value_error(format!(
+                        "{}() requires a code object with {} free vars, not {}",
+                        args.code.obj_name,
+                        closure.len(),
+                        nfree,
+                    )));
+                }
+                Some(closure)
+            }
+            None if nfree > 0 => {
+                return Err(vm.new_type_error("arg 5 (closure) must be tuple".to_owned()));
+            }
+            None => None,
+        };
+        let func = PyFunction::new(
+            args.code,
+            args.globals,
+            closure,
+            args.argdefs.into_option(),
+            None,
+        );
+        // explicit None keeps the code object's name, like CPython
+        if let OptionalArg::Present(Some(name)) = args.name {
+            func.set_name(name);
+        }
+        func.into_pyresult_with_type(vm, cls)
+    }
+}
+
 #[pyclass(module = false, name = "method")]
 #[derive(Debug)]
 pub struct PyBoundMethod {
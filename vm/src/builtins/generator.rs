@@ -29,9 +29,9 @@ impl PyGenerator {
         &self.inner
     }
 
-    pub fn new(frame: FrameRef, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, name: PyStrRef, qualname: PyStrRef) -> Self {
         PyGenerator {
-            inner: Coro::new(frame, Variant::Gen, name),
+            inner: Coro::new(frame, Variant::Gen, name, qualname),
         }
     }
 
@@ -45,6 +45,16 @@ impl PyGenerator {
         self.inner.set_name(name)
     }
 
+    #[pyproperty(magic)]
+    fn qualname(&self) -> PyStrRef {
+        self.inner.qualname()
+    }
+
+    #[pyproperty(magic, setter)]
+    fn set_qualname(&self, qualname: PyStrRef) {
+        self.inner.set_qualname(qualname)
+    }
+
     #[pymethod(magic)]
     fn repr(zelf: PyRef<Self>) -> String {
         zelf.inner.repr(zelf.get_id())
@@ -77,8 +87,13 @@ impl PyGenerator {
     }
 
     #[pyproperty]
-    fn gi_frame(&self, _vm: &VirtualMachine) -> FrameRef {
-        self.inner.frame()
+    fn gi_frame(&self, _vm: &VirtualMachine) -> Option<FrameRef> {
+        // once the generator is exhausted there is no live frame anymore
+        if self.inner.closed() {
+            None
+        } else {
+            Some(self.inner.frame())
+        }
     }
     #[pyproperty]
     fn gi_running(&self, _vm: &VirtualMachine) -> bool {
@@ -521,12 +521,31 @@ impl PyInt {
             OptionalArg::Present(ref value) => {
                 if !vm.is_none(value) {
                     // Only accept int type ndigits
-                    let _ndigits = value.payload_if_subclass::<PyInt>(vm).ok_or_else(|| {
+                    let ndigits = value.payload_if_subclass::<PyInt>(vm).ok_or_else(|| {
                         vm.new_type_error(format!(
                             "'{}' object cannot be interpreted as an integer",
                             value.class().name()
                         ))
                     })?;
+                    if ndigits.value.is_negative() {
+                        // round to the nearest multiple of 10**-ndigits,
+                        // breaking ties towards the even multiple
+                        let ndigits = match (-&ndigits.value).to_u32() {
+                            Some(ndigits) => ndigits,
+                            // 10**-ndigits doesn't fit in memory; the result
+                            // can only be 0 anyway
+                            None => return Ok(PyInt::from(0).into_ref(vm)),
+                        };
+                        let divisor = BigInt::from(10).pow(ndigits);
+                        let (mut quotient, remainder) = zelf.value.div_mod_floor(&divisor);
+                        let double_remainder = &remainder * 2;
+                        if double_remainder > divisor
+                            || (double_remainder == divisor && quotient.is_odd())
+                        {
+                            quotient += 1;
+                        }
+                        return Ok(PyInt::from(quotient * divisor).into_ref(vm));
+                    }
                 } else {
                     return Err(vm.new_type_error(format!(
                         "'{}' object cannot be interpreted as an integer",
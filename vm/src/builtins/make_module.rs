@@ -65,7 +65,7 @@ mod decl {
     #[pyfunction]
     pub fn ascii(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<String> {
         let repr = vm.to_repr(&obj)?;
-        let ascii = to_ascii(repr.as_str());
+        let ascii = to_ascii(repr.as_str()).into_owned();
         Ok(ascii)
     }
 
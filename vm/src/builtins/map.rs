@@ -1,11 +1,13 @@
 use super::PyTypeRef;
 use crate::{
+    common::lock::PyMutex,
     function::PosArgs,
     iterator,
     protocol::PyIter,
     slots::{IteratorIterable, SlotConstructor, SlotIterator},
     PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, VirtualMachine,
 };
+use std::collections::VecDeque;
 
 /// map(func, *iterables) --> map object
 ///
@@ -16,6 +18,10 @@ use crate::{
 pub struct PyMap {
     mapper: PyObjectRef,
     iterators: Vec<PyIter>,
+    // RustPython extension: with chunksize > 1 the mapper is invoked in
+    // batches of up to `chunksize` items, buffering the results
+    chunksize: usize,
+    buffer: PyMutex<VecDeque<PyResult>>,
 }
 
 impl PyValue for PyMap {
@@ -24,12 +30,39 @@ impl PyValue for PyMap {
     }
 }
 
+#[derive(FromArgs)]
+pub struct PyMapNewArgs {
+    #[pyarg(positional)]
+    mapper: PyObjectRef,
+    #[pyarg(flatten)]
+    iterators: PosArgs<PyIter>,
+    #[pyarg(named, default = "1")]
+    chunksize: usize,
+}
+
 impl SlotConstructor for PyMap {
-    type Args = (PyObjectRef, PosArgs<PyIter>);
+    type Args = PyMapNewArgs;
 
-    fn py_new(cls: PyTypeRef, (mapper, iterators): Self::Args, vm: &VirtualMachine) -> PyResult {
+    fn py_new(
+        cls: PyTypeRef,
+        Self::Args {
+            mapper,
+            iterators,
+            chunksize,
+        }: Self::Args,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        if chunksize == 0 {
+            return Err(vm.new_value_error("chunksize must be >= 1".to_owned()));
+        }
         let iterators = iterators.into_vec();
-        PyMap { mapper, iterators }.into_pyresult_with_type(vm, cls)
+        PyMap {
+            mapper,
+            iterators,
+            chunksize,
+            buffer: PyMutex::new(VecDeque::new()),
+        }
+        .into_pyresult_with_type(vm, cls)
     }
 }
 
@@ -37,25 +70,47 @@ impl SlotConstructor for PyMap {
 impl PyMap {
     #[pymethod(magic)]
     fn length_hint(&self, vm: &VirtualMachine) -> PyResult<usize> {
-        self.iterators.iter().try_fold(0, |prev, cur| {
+        let buffered = self.buffer.lock().len();
+        self.iterators.iter().try_fold(buffered, |prev, cur| {
             let cur = iterator::length_hint(vm, cur.as_object().clone())?.unwrap_or(0);
             let max = std::cmp::max(prev, cur);
             Ok(max)
         })
     }
-}
 
-impl IteratorIterable for PyMap {}
-impl SlotIterator for PyMap {
-    fn next(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult {
-        let next_objs = zelf
+    fn next_unbuffered(&self, vm: &VirtualMachine) -> PyResult {
+        let next_objs = self
             .iterators
             .iter()
             .map(|iterator| iterator.next(vm))
             .collect::<Result<Vec<_>, _>>()?;
 
         // the mapper itself can raise StopIteration which does stop the map iteration
-        vm.invoke(&zelf.mapper, next_objs)
+        vm.invoke(&self.mapper, next_objs)
+    }
+}
+
+impl IteratorIterable for PyMap {}
+impl SlotIterator for PyMap {
+    fn next(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult {
+        if zelf.chunksize == 1 {
+            return zelf.next_unbuffered(vm);
+        }
+        let mut buffer = zelf.buffer.lock();
+        if buffer.is_empty() {
+            // pull and invoke a whole batch at once; an error (including
+            // StopIteration) ends the batch but is only delivered after the
+            // results gathered before it, preserving ordering
+            for _ in 0..zelf.chunksize {
+                let result = zelf.next_unbuffered(vm);
+                let stop = result.is_err();
+                buffer.push_back(result);
+                if stop {
+                    break;
+                }
+            }
+        }
+        buffer.pop_front().unwrap()
     }
 }
 
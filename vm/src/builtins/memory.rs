@@ -58,7 +58,13 @@ impl PyMemoryView {
     #[cfg(debug_assertions)]
     fn validate(self) -> Self {
         let options = &self.buffer.options;
-        let bytes_len = options.len * options.itemsize;
+        // for a stepped view the spanned region covers the stride gaps between
+        // items, but not the bytes past the last item
+        let bytes_len = if options.len == 0 {
+            0
+        } else {
+            (options.len - 1) * options.itemsize * self.step.abs() as usize + options.itemsize
+        };
         let buffer_len = self.buffer.internal.obj_bytes().len();
         let t1 = self.stop - self.start == bytes_len;
         let t2 = buffer_len >= self.stop;
@@ -360,7 +360,15 @@ impl PyStr {
             .into_pyobject(vm))
         } else if let Some(radd) = vm.get_method(other.clone(), "__radd__") {
             // hack to get around not distinguishing number add from seq concat
-            vm.invoke(&radd?, (zelf,))
+            let result = vm.invoke(&radd?, (zelf,))?;
+            if result.is(&vm.ctx.not_implemented()) {
+                Err(vm.new_type_error(format!(
+                    "can only concatenate str (not \"{}\") to str",
+                    other.class().name()
+                )))
+            } else {
+                Ok(result)
+            }
         } else {
             Err(vm.new_type_error(format!(
                 "can only concatenate str (not \"{}\") to str",
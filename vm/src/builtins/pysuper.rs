@@ -110,6 +110,21 @@ impl PySuper {
         Ok(Self { typ, obj })
     }
 
+    #[pyproperty(magic)]
+    fn thisclass(&self) -> PyTypeRef {
+        self.typ.clone()
+    }
+
+    #[pyproperty(name = "__self__")]
+    fn get_self(&self) -> Option<PyObjectRef> {
+        self.obj.as_ref().map(|(obj, _)| obj.clone())
+    }
+
+    #[pyproperty(magic)]
+    fn self_class(&self) -> Option<PyTypeRef> {
+        self.obj.as_ref().map(|(_, typ)| typ.clone())
+    }
+
     #[pymethod(magic)]
     fn repr(&self) -> String {
         let typname = &self.typ.name();
@@ -242,7 +242,7 @@ impl PyType {
                     |zelf, name, vm| vm.call_special_method(zelf, "__getattribute__", (name,));
                 update_slot!(getattro, func);
             }
-            "__setattr__" => {
+            "__setattr__" | "__delattr__" => {
                 let func: slots::SetattroFunc = |zelf, name, value, vm| {
                     match value {
                         Some(value) => {
@@ -72,12 +72,17 @@ impl SlotConstructor for PyNotImplemented {
 
 #[pyimpl(with(SlotConstructor))]
 impl PyNotImplemented {
-    // TODO: As per https://bugs.python.org/issue35712, using NotImplemented
-    // in boolean contexts will need to raise a DeprecationWarning in 3.9
-    // and, eventually, a TypeError.
+    // As per https://bugs.python.org/issue35712, using NotImplemented in a
+    // boolean context is deprecated and will eventually become a TypeError.
     #[pymethod(magic)]
-    fn bool(&self) -> bool {
-        true
+    fn bool(&self, vm: &VirtualMachine) -> PyResult<bool> {
+        crate::stdlib::warnings::warn(
+            vm.ctx.exceptions.deprecation_warning.clone(),
+            "NotImplemented should not be used in a boolean context".to_owned(),
+            1,
+            vm,
+        )?;
+        Ok(true)
     }
 
     #[pymethod(magic)]
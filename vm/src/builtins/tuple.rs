@@ -197,7 +197,7 @@ impl PyTuple {
     #[pymethod(name = "__rmul__")]
     #[pymethod(magic)]
     fn mul(zelf: PyRef<Self>, value: isize, vm: &VirtualMachine) -> PyResult<PyRef<Self>> {
-        Ok(if zelf.elements.is_empty() || value == 0 {
+        Ok(if zelf.elements.is_empty() || value <= 0 {
             vm.ctx.empty_tuple.clone()
         } else if value == 1 && zelf.class().is(&vm.ctx.types.tuple_type) {
             // Special case: when some `tuple` is multiplied by `1`,
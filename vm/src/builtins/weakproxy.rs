@@ -2,7 +2,7 @@ use super::{PyStrRef, PyTypeRef, PyWeak};
 use crate::{
     function::OptionalArg,
     slots::{SlotConstructor, SlotSetattro},
-    PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, VirtualMachine,
+    ItemProtocol, PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, VirtualMachine,
 };
 
 #[pyclass(module = false, name = "weakproxy")]
@@ -45,16 +45,61 @@ impl SlotConstructor for PyWeakProxy {
 
 #[pyimpl(with(SlotSetattro, SlotConstructor))]
 impl PyWeakProxy {
+    fn try_upgrade(&self, vm: &VirtualMachine) -> PyResult {
+        self.weak.upgrade().ok_or_else(|| {
+            vm.new_exception_msg(
+                vm.ctx.exceptions.reference_error.clone(),
+                "weakly-referenced object no longer exists".to_owned(),
+            )
+        })
+    }
+
     // TODO: callbacks
     #[pymethod(magic)]
     fn getattr(&self, attr_name: PyObjectRef, vm: &VirtualMachine) -> PyResult {
-        match self.weak.upgrade() {
-            Some(obj) => vm.get_attribute(obj, attr_name),
-            None => Err(vm.new_exception_msg(
-                vm.ctx.exceptions.reference_error.clone(),
-                "weakly-referenced object no longer exists".to_owned(),
-            )),
-        }
+        let obj = self.try_upgrade(vm)?;
+        vm.get_attribute(obj, attr_name)
+    }
+
+    #[pymethod(magic)]
+    fn str(&self, vm: &VirtualMachine) -> PyResult<PyStrRef> {
+        let obj = self.try_upgrade(vm)?;
+        vm.to_str(&obj)
+    }
+
+    #[pymethod(magic)]
+    fn len(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        let obj = self.try_upgrade(vm)?;
+        vm.obj_len(&obj)
+    }
+
+    #[pymethod(magic)]
+    fn contains(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let obj = self.try_upgrade(vm)?;
+        vm._membership(obj, needle)
+    }
+
+    #[pymethod(magic)]
+    fn getitem(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+        let obj = self.try_upgrade(vm)?;
+        obj.get_item(needle, vm)
+    }
+
+    #[pymethod(magic)]
+    fn setitem(
+        &self,
+        needle: PyObjectRef,
+        value: PyObjectRef,
+        vm: &VirtualMachine,
+    ) -> PyResult<()> {
+        let obj = self.try_upgrade(vm)?;
+        obj.set_item(needle, value, vm)
+    }
+
+    #[pymethod(magic)]
+    fn delitem(&self, needle: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
+        let obj = self.try_upgrade(vm)?;
+        obj.del_item(needle, vm)
     }
 }
 
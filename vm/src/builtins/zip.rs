@@ -3,7 +3,7 @@ use crate::{
     function::PosArgs,
     protocol::PyIter,
     slots::{IteratorIterable, SlotConstructor, SlotIterator},
-    PyClassImpl, PyContext, PyRef, PyResult, PyValue, VirtualMachine,
+    PyClassImpl, PyContext, PyObjectRef, PyRef, PyResult, PyValue, TypeProtocol, VirtualMachine,
 };
 
 #[pyclass(module = false, name = "zip")]
@@ -28,7 +28,22 @@ impl SlotConstructor for PyZip {
 }
 
 #[pyimpl(with(SlotIterator, SlotConstructor), flags(BASETYPE))]
-impl PyZip {}
+impl PyZip {
+    #[pymethod(magic)]
+    fn reduce(zelf: PyRef<Self>, vm: &VirtualMachine) -> PyObjectRef {
+        // reconstruct with the instance's own class so subclasses survive
+        // the round-trip
+        let iterators = zelf
+            .iterators
+            .iter()
+            .map(|iterator| iterator.as_object().clone())
+            .collect();
+        vm.ctx.new_tuple(vec![
+            zelf.as_object().clone_class().into_object(),
+            vm.ctx.new_tuple(iterators),
+        ])
+    }
+}
 
 impl IteratorIterable for PyZip {}
 impl SlotIterator for PyZip {
@@ -40,10 +40,11 @@ pub struct Coro {
     exception: PyMutex<Option<PyBaseExceptionRef>>,
     variant: Variant,
     name: PyMutex<PyStrRef>,
+    qualname: PyMutex<PyStrRef>,
 }
 
 impl Coro {
-    pub fn new(frame: FrameRef, variant: Variant, name: PyStrRef) -> Self {
+    pub fn new(frame: FrameRef, variant: Variant, name: PyStrRef, qualname: PyStrRef) -> Self {
         Coro {
             frame,
             closed: AtomicCell::new(false),
@@ -51,6 +52,7 @@ impl Coro {
             exception: PyMutex::default(),
             variant,
             name: PyMutex::new(name),
+            qualname: PyMutex::new(qualname),
         }
     }
 
@@ -168,11 +170,17 @@ impl Coro {
     pub fn set_name(&self, name: PyStrRef) {
         *self.name.lock() = name;
     }
+    pub fn qualname(&self) -> PyStrRef {
+        self.qualname.lock().clone()
+    }
+    pub fn set_qualname(&self, qualname: PyStrRef) {
+        *self.qualname.lock() = qualname;
+    }
     pub fn repr(&self, id: usize) -> String {
         format!(
             "<{} object {} at {:#x}>",
             self.variant.name(),
-            self.name.lock(),
+            self.qualname.lock(),
             id
         )
     }
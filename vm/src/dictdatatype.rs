@@ -121,9 +121,20 @@ struct GenIndexes {
     mask: HashIndex,
 }
 
+/// A per-process salt mixed into the probe sequence so that the slots tried
+/// for a given hash differ from run to run. Iteration order is backed by
+/// `entries` and stays insertion order regardless.
+#[cfg(feature = "probe-order-fuzzing")]
+fn probe_salt() -> HashValue {
+    static SALT: once_cell::sync::OnceCell<HashValue> = once_cell::sync::OnceCell::new();
+    *SALT.get_or_init(rand::random)
+}
+
 impl GenIndexes {
     fn new(hash: HashValue, mask: HashIndex) -> Self {
         let hash = hash.abs();
+        #[cfg(feature = "probe-order-fuzzing")]
+        let hash = (hash ^ probe_salt()) & HashValue::MAX;
         Self {
             idx: hash,
             perturb: hash,
@@ -805,6 +816,25 @@ mod tests {
         })
     }
 
+    #[cfg(feature = "probe-order-fuzzing")]
+    #[test]
+    fn test_insertion_order_with_probe_fuzzing() {
+        Interpreter::default().enter(|vm| {
+            let dict = Dict::default();
+            let keys = ["one", "two", "three", "four", "five"];
+            for key in &keys {
+                let key = vm.ctx.new_utf8_str(*key);
+                dict.insert(&vm, key, vm.ctx.none()).unwrap();
+            }
+            let iterated = dict
+                .keys()
+                .iter()
+                .map(|k| k.downcast_ref::<crate::builtins::PyStr>().unwrap().to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(iterated, keys);
+        })
+    }
+
     macro_rules! hash_tests {
         ($($name:ident: $example_hash:expr,)*) => {
             $(
@@ -170,9 +170,21 @@ pub fn write_exception_inner<W: Write>(
     exc: &PyBaseExceptionRef,
 ) -> Result<(), W::Error> {
     if let Some(tb) = exc.traceback.read().clone() {
-        writeln!(output, "Traceback (most recent call last):")?;
-        for tb in tb.iter() {
-            write_traceback_entry(output, &tb)?;
+        let tracebacklimit = vm
+            .get_attribute_opt(vm.sys_module.clone(), "tracebacklimit")
+            .unwrap_or(None)
+            .and_then(|limit| isize::try_from_object(vm, limit).ok());
+        // a zero or negative limit suppresses the traceback entirely, leaving
+        // just the exception line
+        if tracebacklimit.map_or(true, |limit| limit > 0) {
+            writeln!(output, "Traceback (most recent call last):")?;
+            let entries: Vec<_> = tb.iter().collect();
+            // like CPython, keep the innermost `limit` frames
+            let skip = tracebacklimit
+                .map_or(0, |limit| entries.len().saturating_sub(limit as usize));
+            for tb_entry in entries.iter().skip(skip) {
+                write_traceback_entry(output, tb_entry)?;
+            }
         }
     }
 
@@ -897,13 +909,15 @@ impl serde::Serialize for SerializeException<'_> {
             self.exc.traceback().map(Tracebacks)
         };
         struc.serialize_field("traceback", &tbs)?;
+        let cause = self.exc.cause();
         struc.serialize_field(
             "cause",
-            &self.exc.cause().as_ref().map(|e| Self::new(self.vm, e)),
+            &cause.as_ref().map(|e| SerializeException::new(self.vm, e)),
         )?;
+        let context = self.exc.context();
         struc.serialize_field(
             "context",
-            &self.exc.context().as_ref().map(|e| Self::new(self.vm, e)),
+            &context.as_ref().map(|e| SerializeException::new(self.vm, e)),
         )?;
         struc.serialize_field("suppress_context", &self.exc.get_suppress_context())?;
 
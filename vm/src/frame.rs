@@ -947,15 +947,12 @@ impl ExecutingFrame<'_> {
                 let args = self.collect_ex_args(vm, *has_kwargs)?;
                 self.execute_method_call(args, vm)
             }
-            bytecode::Instruction::Jump { target } => {
-                self.jump(*target);
-                Ok(None)
-            }
+            bytecode::Instruction::Jump { target } => self.counted_jump(vm, *target),
             bytecode::Instruction::JumpIfTrue { target } => {
                 let obj = self.pop_value();
                 let value = obj.try_to_bool(vm)?;
                 if value {
-                    self.jump(*target);
+                    return self.counted_jump(vm, *target);
                 }
                 Ok(None)
             }
@@ -964,7 +961,7 @@ impl ExecutingFrame<'_> {
                 let obj = self.pop_value();
                 let value = obj.try_to_bool(vm)?;
                 if !value {
-                    self.jump(*target);
+                    return self.counted_jump(vm, *target);
                 }
                 Ok(None)
             }
@@ -973,10 +970,9 @@ impl ExecutingFrame<'_> {
                 let obj = self.last_value();
                 let value = obj.try_to_bool(vm)?;
                 if value {
-                    self.jump(*target);
-                } else {
-                    self.pop_value();
+                    return self.counted_jump(vm, *target);
                 }
+                self.pop_value();
                 Ok(None)
             }
 
@@ -984,10 +980,9 @@ impl ExecutingFrame<'_> {
                 let obj = self.last_value();
                 let value = obj.try_to_bool(vm)?;
                 if !value {
-                    self.jump(*target);
-                } else {
-                    self.pop_value();
+                    return self.counted_jump(vm, *target);
                 }
+                self.pop_value();
                 Ok(None)
             }
 
@@ -1178,8 +1173,7 @@ impl ExecutingFrame<'_> {
                         return Ok(None);
                     }
                     UnwindReason::Continue { target } => {
-                        self.jump(target);
-                        return Ok(None);
+                        return self.counted_jump(vm, target);
                     }
                     _ => {
                         self.pop_block();
@@ -1511,6 +1505,18 @@ impl ExecutingFrame<'_> {
         self.update_lasti(|i| *i = target_pc);
     }
 
+    /// Like `jump`, but charges backward jumps (loop back edges) against the
+    /// vm's instruction budget when `PySettings::max_instructions` is set.
+    #[inline]
+    fn counted_jump(&mut self, vm: &VirtualMachine, label: bytecode::Label) -> FrameResult {
+        let backwards = label.0 <= self.lasti();
+        self.jump(label);
+        if backwards {
+            vm.check_instruction_budget()?;
+        }
+        Ok(None)
+    }
+
     /// The top of stack contains the iterator, lets push it forward
     fn execute_for_iter(&mut self, vm: &VirtualMachine, target: bytecode::Label) -> FrameResult {
         let top_of_stack = PyIter::new(self.last_value());
@@ -3308,7 +3308,7 @@ mod _io {
 
         #[pymethod]
         fn close(self, vm: &VirtualMachine) -> PyResult<()> {
-            let _ = self.try_resizable(vm)?;
+            let _resizable = self.try_resizable(vm)?;
             self.closed.store(true);
             Ok(())
         }
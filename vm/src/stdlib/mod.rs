@@ -40,7 +40,7 @@ mod syslog;
 mod thread;
 mod time;
 mod unicodedata;
-mod warnings;
+pub(crate) mod warnings;
 mod weakref;
 mod zlib;
 
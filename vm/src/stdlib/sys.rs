@@ -5,6 +5,7 @@ use crate::builtins::{PyStr, PyStrRef, PyTypeRef};
 use crate::common::{
     ascii,
     hash::{PyHash, PyUHash},
+    str::repr_for_encoding,
 };
 use crate::frame::FrameRef;
 use crate::function::{FuncArgs, OptionalArg, PosArgs};
@@ -262,8 +263,20 @@ fn sys_displayhook(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<()> {
     }
     // set to none to avoid recursion while printing
     vm.set_attr(&vm.builtins, "_", vm.ctx.none())?;
-    // TODO: catch encoding errors
-    let repr = vm.to_repr(&obj)?.into_object();
+    let repr = vm.to_repr(&obj)?;
+    // escape anything stdout's encoding can't represent, like CPython does
+    // when printing to a non-unicode terminal
+    let encoding = get_stdout(vm)
+        .ok()
+        .and_then(|stdout| vm.get_attribute_opt(stdout, "encoding").ok().flatten())
+        .and_then(|encoding| encoding.downcast::<PyStr>().ok());
+    let repr = match encoding {
+        Some(encoding) => match repr_for_encoding(repr.as_str(), encoding.as_str()) {
+            std::borrow::Cow::Owned(escaped) => vm.ctx.new_utf8_str(escaped),
+            std::borrow::Cow::Borrowed(_) => repr.into_object(),
+        },
+        None => repr.into_object(),
+    };
     builtins::print(PosArgs::new(vec![repr]), Default::default(), vm)?;
     vm.set_attr(&vm.builtins, "_", obj)?;
     Ok(())
@@ -1,5 +1,23 @@
 pub(crate) use _warnings::make_module;
 
+use crate::{builtins::PyTypeRef, PyResult, VirtualMachine};
+
+/// Raise a warning from Rust code, going through the Python-level `warnings`
+/// module so that warning filters (e.g. `-W error`) are applied.
+pub fn warn(
+    category: PyTypeRef,
+    message: String,
+    stack_level: usize,
+    vm: &VirtualMachine,
+) -> PyResult<()> {
+    // TODO: use rust implementations of the warnings machinery once _warnings
+    // implements filtering
+    let module = vm.import("warnings", None, 0)?;
+    let warn = vm.get_attribute(module, "warn")?;
+    vm.invoke(&warn, (message, category, stack_level))?;
+    Ok(())
+}
+
 #[pymodule]
 mod _warnings {
     use crate::{
@@ -59,6 +59,7 @@ pub struct VirtualMachine {
     pub trace_func: RefCell<PyObjectRef>,
     pub use_tracing: Cell<bool>,
     pub recursion_limit: Cell<usize>,
+    pub(crate) instruction_budget: Cell<Option<u64>>,
     pub signal_handlers: Option<Box<RefCell<[Option<PyObjectRef>; NSIG]>>>,
     pub repr_guards: RefCell<HashSet<usize>>,
     pub state: PyRc<PyGlobalState>,
@@ -192,6 +193,16 @@ pub struct PySettings {
     /// -u, PYTHONUNBUFFERED=x
     // TODO: use this; can TextIOWrapper even work with a non-buffered?
     pub stdio_unbuffered: bool,
+
+    /// RustPython extension for embedders: abort execution with a RuntimeError
+    /// once this many backward jumps have been executed, so untrusted code
+    /// can't loop forever. `None` (the default) means unlimited.
+    pub max_instructions: Option<u64>,
+
+    /// RustPython extension for embedders: render `repr(float)` with this many
+    /// significant digits (like `%.<n>g`) instead of the shortest string that
+    /// round-trips. `None` (the default) keeps the round-trip behavior.
+    pub float_repr_precision: Option<usize>,
 }
 
 /// Trace events for sys.settrace and sys.setprofile.
@@ -236,6 +247,8 @@ impl Default for PySettings {
             argv: vec![],
             hash_seed: None,
             stdio_unbuffered: false,
+            max_instructions: None,
+            float_repr_precision: None,
         }
     }
 }
@@ -285,6 +298,8 @@ impl VirtualMachine {
             trace_func,
             use_tracing: Cell::new(false),
             recursion_limit: Cell::new(if cfg!(debug_assertions) { 256 } else { 1000 }),
+            // armed once initialization is done; see `initialize`
+            instruction_budget: Cell::new(None),
             signal_handlers: Some(Box::new(signal_handlers)),
             repr_guards: RefCell::default(),
             state: PyRc::new(PyGlobalState {
@@ -382,6 +397,10 @@ impl VirtualMachine {
 
         self.expect_pyresult(res, "initialization failed");
 
+        // initialization itself runs a fair amount of Python code; only arm the
+        // instruction budget for the code that comes after it
+        self.instruction_budget.set(self.state.settings.max_instructions);
+
         self.initialized = true;
     }
 
@@ -461,6 +480,9 @@ impl VirtualMachine {
             trace_func: RefCell::new(self.ctx.none()),
             use_tracing: Cell::new(false),
             recursion_limit: self.recursion_limit.clone(),
+            // inherit the remaining budget rather than re-arming the full
+            // amount, so sandboxed code can't extend it by spawning threads
+            instruction_budget: self.instruction_budget.clone(),
             signal_handlers: None,
             repr_guards: RefCell::default(),
             state: self.state.clone(),
@@ -509,7 +531,14 @@ impl VirtualMachine {
     ) -> PyResult<R> {
         self.check_recursive_call("")?;
         self.frames.borrow_mut().push(frame.clone());
-        let result = f(frame);
+        let result = (|| {
+            // fire the trace events with the new frame current, so the hook
+            // sees the frame being entered/left rather than its caller
+            self.trace_event(TraceEvent::Call)?;
+            let result = f(frame);
+            self.trace_event(TraceEvent::Return)?;
+            result
+        })();
         // defer dec frame
         let _popped = self.frames.borrow_mut().pop();
         result
@@ -527,6 +556,20 @@ impl VirtualMachine {
         }
     }
 
+    /// Charge one backward jump against the budget configured through
+    /// `PySettings::max_instructions`. A no-op when no budget is set.
+    pub(crate) fn check_instruction_budget(&self) -> PyResult<()> {
+        if let Some(remaining) = self.instruction_budget.get() {
+            if remaining == 0 {
+                return Err(
+                    self.new_runtime_error("maximum instruction count exceeded".to_owned())
+                );
+            }
+            self.instruction_budget.set(Some(remaining - 1));
+        }
+        Ok(())
+    }
+
     pub fn current_frame(&self) -> Option<Ref<FrameRef>> {
         let frames = self.frames.borrow();
         if frames.is_empty() {
@@ -1183,12 +1226,7 @@ impl VirtualMachine {
         vm_trace!("Invoke: {:?} {:?}", callable, args);
         let slot_call = callable.class().mro_find_map(|cls| cls.slots.call.load());
         match slot_call {
-            Some(slot_call) => {
-                self.trace_event(TraceEvent::Call)?;
-                let result = slot_call(callable, args, self);
-                self.trace_event(TraceEvent::Return)?;
-                result
-            }
+            Some(slot_call) => slot_call(callable, args, self),
             None => Err(self.new_type_error(format!(
                 "'{}' object is not callable",
                 callable.class().name()
@@ -2214,8 +2252,9 @@ impl PyThread {
 
 #[cfg(test)]
 mod tests {
-    use super::Interpreter;
+    use super::{InitParameter, Interpreter, PySettings};
     use crate::builtins::{int, PyStr};
+    use crate::pyobject::{IdProtocol, TypeProtocol};
     use num_bigint::ToBigInt;
 
     #[test]
@@ -2229,6 +2268,70 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_max_instructions_stops_infinite_loop() {
+        let settings = PySettings {
+            max_instructions: Some(100),
+            ..Default::default()
+        };
+        Interpreter::new(settings, InitParameter::External).enter(|vm| {
+            let code = vm
+                .compile(
+                    "while True:\n    pass\n",
+                    crate::compile::Mode::Exec,
+                    "<budget>".to_owned(),
+                )
+                .unwrap();
+            let scope = vm.new_scope_with_builtins();
+            let err = vm.run_code_obj(code, scope).unwrap_err();
+            assert!(err.isinstance(&vm.ctx.exceptions.runtime_error));
+        })
+    }
+
+    #[test]
+    fn test_import_frozen_module_from_serialized_bytecode() {
+        let code = crate::compile::compile(
+            "initialized = True\n",
+            crate::compile::Mode::Exec,
+            "frozen_mod.py".to_owned(),
+            Default::default(),
+        )
+        .unwrap();
+        // round-trip through the serialized form an embedder would bundle
+        let code = crate::bytecode::CodeObject::from_bytes(&code.to_bytes()).unwrap();
+        let interp = Interpreter::new_with_init(PySettings::default(), |vm| {
+            vm.add_frozen(std::iter::once((
+                "frozen_mod".to_owned(),
+                crate::bytecode::FrozenModule {
+                    code,
+                    package: false,
+                },
+            )));
+            InitParameter::External
+        });
+        interp.enter(|vm| {
+            let module = vm.import("frozen_mod", None, 0).unwrap();
+            let initialized = vm.get_attribute(module, "initialized").unwrap();
+            assert!(initialized.is(&vm.ctx.true_value));
+        })
+    }
+
+    #[test]
+    fn test_float_repr_precision_setting() {
+        Interpreter::default().enter(|vm| {
+            let f = vm.ctx.new_float(0.1);
+            assert_eq!(vm.to_repr(&f).unwrap().as_str(), "0.1");
+        });
+        let settings = PySettings {
+            float_repr_precision: Some(17),
+            ..Default::default()
+        };
+        Interpreter::new(settings, InitParameter::External).enter(|vm| {
+            let f = vm.ctx.new_float(0.1);
+            assert_eq!(vm.to_repr(&f).unwrap().as_str(), "0.10000000000000001");
+        });
+    }
+
     #[test]
     fn test_multiply_str() {
         Interpreter::default().enter(|vm| {